@@ -0,0 +1,45 @@
+use crate::{BeanInstance, WeakBeanProviderRef};
+use std::any::Any;
+
+/// Implemented by beans that want to know the name they are registered under. Opt in with
+/// `#[component(bean_name_aware)]`; the container calls `set_bean_name` right after
+/// construction, before any post-processor or `#[post_construct]` callback.
+///
+/// Beans are shared immutably, so implementations typically store the name in a
+/// `OnceLock<String>`.
+pub trait BeanNameAware {
+    fn set_bean_name(&self, name: &str);
+}
+
+/// Implemented by beans that need the container for dynamic lookups. Opt in with
+/// `#[component(container_aware)]`; the container calls `set_container` right after
+/// construction, before any post-processor or `#[post_construct]` callback.
+///
+/// The handle is weak so that a bean holding it does not keep the container alive; upgrade it
+/// for each lookup.
+pub trait ContainerAware {
+    fn set_container(&self, container: WeakBeanProviderRef);
+}
+
+/// Hands a freshly constructed bean its name. Ignores instances of other types.
+pub type BeanNameAwareCallback = fn(bean: &BeanInstance, name: &str);
+
+/// Hands a freshly constructed bean a handle to its container. Ignores instances of other types.
+pub type ContainerAwareCallback = fn(bean: &BeanInstance, container: &WeakBeanProviderRef);
+
+/// The [`BeanNameAwareCallback`] for beans of type `T`.
+pub fn notify_bean_name<T: BeanNameAware + Any>(bean: &BeanInstance, name: &str) {
+    if let Some(bean) = bean.downcast_ref::<T>() {
+        bean.set_bean_name(name);
+    }
+}
+
+/// The [`ContainerAwareCallback`] for beans of type `T`.
+pub fn notify_container<T: ContainerAware + Any>(
+    bean: &BeanInstance,
+    container: &WeakBeanProviderRef,
+) {
+    if let Some(bean) = bean.downcast_ref::<T>() {
+        bean.set_container(container.clone());
+    }
+}
@@ -0,0 +1,29 @@
+//! Registration conditions generated by `#[conditional_on_property]`,
+//! `#[conditional_on_missing_bean]`, `#[conditional_on_feature]` and `#[profile]`.
+
+use crate::{TypeIdGetter, TypeNameGetter};
+
+/// A condition that must hold for a component to be registered. The container evaluates
+/// every condition of a bean during `initialize` and skips the bean if any of them fails.
+#[derive(Clone, Copy)]
+pub enum ConditionMetadata {
+    /// The property `name` is set and equals `having_value`, or is not `"false"` when no value
+    /// is given. A missing property matches only if `match_if_missing` is set.
+    OnProperty {
+        name: &'static str,
+        having_value: Option<&'static str>,
+        match_if_missing: bool,
+    },
+    /// At least one of the profiles is active; `"!name"` matches when `name` is not active.
+    Profile(&'static [&'static str]),
+    /// No other bean is registered under the type, e.g. a fallback implementation.
+    OnMissingBean {
+        type_id: TypeIdGetter,
+        type_name: TypeNameGetter,
+    },
+    /// The cargo feature was enabled for the crate declaring the component.
+    OnFeature {
+        feature: &'static str,
+        enabled: bool,
+    },
+}
@@ -1,4 +1,5 @@
-use std::any::TypeId;
+use crate::type_info::{Suggestions, TypeInfo};
+use std::error::Error as StdError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -8,18 +9,80 @@ pub enum ConstructorError {
 
     #[error("Constructor error with message: {0}.")]
     BaseMsgError(String),
-    
+
     #[error("Container has not been initialized yet.")]
     ContainerNotInitialized,
 
-    #[error("Bean definition not found for type ID: {0:?}")]
-    BeanNotFoundByType(TypeId),
-
-    #[error("Bean definition not found for name: {0}")]
-    BeanNotFoundByName(String),
-    
-    #[error(
-        "Multiple beans found for type ID: {0:?}. Use qualifiers or @Primary to disambiguate."
-    )]
-    MultipleBeansFound(TypeId),
-}
\ No newline at end of file
+    #[error("Bean definition not found for type {bean_type}{suggestions}")]
+    BeanNotFoundByType {
+        bean_type: TypeInfo,
+        suggestions: Suggestions,
+    },
+
+    #[error("Bean definition not found for name: {name}{suggestions}")]
+    BeanNotFoundByName {
+        name: String,
+        suggestions: Suggestions,
+    },
+
+    #[error("Multiple beans found for type {0}. Use qualifiers or @Primary to disambiguate.")]
+    MultipleBeansFound(TypeInfo),
+
+    #[error("No bean of type {bean_type} matches qualifier '{qualifier}'{suggestions}")]
+    BeanNotFoundByQualifier {
+        bean_type: TypeInfo,
+        qualifier: String,
+        suggestions: Suggestions,
+    },
+
+    #[error("Bean resolved for type {0} could not be downcast to the requested type.")]
+    TypeMismatch(TypeInfo),
+
+    /// The dependency of type `bean_type` exists but could not be provided, e.g. because its
+    /// own construction failed. `source` is the provider's error.
+    #[error("Failed to provide dependency of type {bean_type}")]
+    DependencyFailed {
+        bean_type: TypeInfo,
+        #[source]
+        source: Box<dyn StdError + Send + Sync>,
+    },
+
+    /// An error raised by user code, such as a lifecycle callback.
+    #[error(transparent)]
+    Other(Box<dyn StdError + Send + Sync>),
+}
+
+impl ConstructorError {
+    /// Names `bean_type` in this error if the provider that raised it only knew its `TypeId`.
+    pub fn with_type_name(self, bean_type: TypeInfo) -> Self {
+        match self {
+            ConstructorError::BeanNotFoundByType {
+                bean_type: unnamed,
+                suggestions,
+            } => ConstructorError::BeanNotFoundByType {
+                bean_type: unnamed.or_named(bean_type),
+                suggestions,
+            },
+            ConstructorError::MultipleBeansFound(unnamed) => {
+                ConstructorError::MultipleBeansFound(unnamed.or_named(bean_type))
+            }
+            ConstructorError::BeanNotFoundByQualifier {
+                bean_type: unnamed,
+                qualifier,
+                suggestions,
+            } => ConstructorError::BeanNotFoundByQualifier {
+                bean_type: unnamed.or_named(bean_type),
+                qualifier,
+                suggestions,
+            },
+            ConstructorError::DependencyFailed {
+                bean_type: unnamed,
+                source,
+            } => ConstructorError::DependencyFailed {
+                bean_type: unnamed.or_named(bean_type),
+                source,
+            },
+            other => other,
+        }
+    }
+}
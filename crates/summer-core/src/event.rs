@@ -0,0 +1,47 @@
+//! Application events: the types published through the container's event bus and the
+//! listener metadata generated by `#[event_listener]`.
+
+use crate::{BeanInstance, ConstructorError, TypeIdGetter, TypeNameGetter};
+use std::any::Any;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Implemented by every type that can be published as an event (like Spring's
+/// `ApplicationEvent`).
+pub trait ApplicationEvent: Any + Send + Sync {}
+
+/// A shared, dynamically-typed event as it travels to the listeners.
+pub type EventInstance = Arc<dyn Any + Send + Sync>;
+
+/// Future returned by an async event listener.
+pub type EventFuture = Pin<Box<dyn Future<Output = Result<(), ConstructorError>> + Send>>;
+
+/// Receives every published event of type `E`. Register implementations with
+/// `IocContainer::add_application_listener`; beans can use `#[event_listener]` methods instead.
+///
+/// An error stops the delivery of the event and is returned to the publisher.
+pub trait ApplicationListener<E: ApplicationEvent>: Send + Sync {
+    fn on_event(&self, event: &E) -> Result<(), ConstructorError>;
+}
+
+/// A type-erased `#[event_listener]` method bound to one bean type and one event type.
+#[derive(Clone, Copy)]
+pub enum EventListenerCallback {
+    /// Calls a plain method on the bean.
+    Sync(fn(bean: &BeanInstance, event: &EventInstance) -> Result<(), ConstructorError>),
+    /// Calls an `async fn` on the bean; async publishing awaits the returned future.
+    Async(fn(bean: BeanInstance, event: EventInstance) -> EventFuture),
+}
+
+/// Compile-time description of an `#[event_listener]` method.
+pub struct EventListenerMetadata {
+    /// The bean whose instances receive the events.
+    pub bean_type_id: TypeIdGetter,
+    pub event_type_id: TypeIdGetter,
+    pub event_type_name: TypeNameGetter,
+    pub method_name: &'static str,
+    pub callback: EventListenerCallback,
+}
+
+inventory::collect!(EventListenerMetadata);
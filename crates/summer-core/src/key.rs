@@ -0,0 +1,50 @@
+//! Typed bean keys generated by `#[component]`, e.g. `UserService::BEAN`.
+
+use std::any::Any;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// Implemented by `#[component]` for every component struct.
+pub trait Bean: Any + Send + Sync {
+    /// The name the component is registered under.
+    const BEAN_NAME: &'static str;
+}
+
+/// Zero-sized handle to the bean of the component `T`, emitted by `#[component]` as
+/// `T::BEAN`. Lookups through a key (`IocContainer::get(UserService::BEAN)`) return an
+/// `Arc<T>` without spelling out the bean name, so a misspelled component is a compile error.
+pub struct BeanKey<T: ?Sized>(PhantomData<fn() -> Arc<T>>);
+
+impl<T: ?Sized> BeanKey<T> {
+    pub const fn new() -> Self {
+        BeanKey(PhantomData)
+    }
+}
+
+impl<T: ?Sized> Default for BeanKey<T> {
+    fn default() -> Self {
+        BeanKey::new()
+    }
+}
+
+impl<T: Bean> BeanKey<T> {
+    /// The name of the bean this key refers to.
+    pub const fn name(self) -> &'static str {
+        T::BEAN_NAME
+    }
+}
+
+impl<T: ?Sized> Clone for BeanKey<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for BeanKey<T> {}
+
+impl<T: Bean> fmt::Debug for BeanKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BeanKey({})", T::BEAN_NAME)
+    }
+}
@@ -0,0 +1,112 @@
+//! Deferred dependency handles: [`ObjectProvider`] and [`Lazy`].
+//!
+//! Both hold a weak reference to the provider and resolve the bean only when asked, so a bean
+//! can depend on another that (directly or indirectly) depends back on it.
+
+use crate::resolve::{
+    optional_bean, resolve_bean, resolve_bean_dyn, resolve_qualified_bean,
+    resolve_qualified_bean_dyn,
+};
+use crate::{BeanProvider, BeanProviderRef, ConstructorError, WeakBeanProviderRef};
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+type Resolver<T> = fn(&dyn BeanProvider, Option<&str>) -> Result<Arc<T>, ConstructorError>;
+
+/// Resolves a bean of type `T` from the container on every call to [`ObjectProvider::get`]
+/// (like Spring's `ObjectProvider`). Prototype beans yield a new instance each time.
+pub struct ObjectProvider<T: ?Sized> {
+    provider: WeakBeanProviderRef,
+    qualifier: Option<String>,
+    resolve: Resolver<T>,
+}
+
+impl<T: Any + Send + Sync> ObjectProvider<T> {
+    pub fn new(provider: &BeanProviderRef, qualifier: Option<&str>) -> Self {
+        Self::with_resolver(provider, qualifier, |provider, qualifier| match qualifier {
+            Some(qualifier) => resolve_qualified_bean(provider, qualifier),
+            None => resolve_bean(provider),
+        })
+    }
+}
+
+impl<T: ?Sized + Send + Sync + 'static> ObjectProvider<T> {
+    /// Provider for a trait object `T`, e.g. `ObjectProvider<dyn Repository>`.
+    pub fn new_dyn(provider: &BeanProviderRef, qualifier: Option<&str>) -> Self {
+        Self::with_resolver(provider, qualifier, |provider, qualifier| match qualifier {
+            Some(qualifier) => resolve_qualified_bean_dyn(provider, qualifier),
+            None => resolve_bean_dyn(provider),
+        })
+    }
+
+    fn with_resolver(
+        provider: &BeanProviderRef,
+        qualifier: Option<&str>,
+        resolve: Resolver<T>,
+    ) -> Self {
+        ObjectProvider {
+            provider: Arc::downgrade(provider),
+            qualifier: qualifier.map(str::to_string),
+            resolve,
+        }
+    }
+
+    /// Resolves the bean. Fails with `ContainerNotInitialized` once the container is gone.
+    pub fn get(&self) -> Result<Arc<T>, ConstructorError> {
+        let provider = self
+            .provider
+            .upgrade()
+            .ok_or(ConstructorError::ContainerNotInitialized)?;
+        (self.resolve)(&*provider, self.qualifier.as_deref())
+    }
+
+    /// Like [`ObjectProvider::get`], but yields `None` when no matching bean exists.
+    pub fn get_if_available(&self) -> Result<Option<Arc<T>>, ConstructorError> {
+        optional_bean(self.get())
+    }
+}
+
+impl<T: ?Sized> Clone for ObjectProvider<T> {
+    fn clone(&self) -> Self {
+        ObjectProvider {
+            provider: self.provider.clone(),
+            qualifier: self.qualifier.clone(),
+            resolve: self.resolve,
+        }
+    }
+}
+
+/// Resolves a bean of type `T` on first use and keeps it for later calls.
+pub struct Lazy<T: ?Sized> {
+    provider: ObjectProvider<T>,
+    bean: OnceLock<Arc<T>>,
+}
+
+impl<T: Any + Send + Sync> Lazy<T> {
+    pub fn new(provider: &BeanProviderRef, qualifier: Option<&str>) -> Self {
+        Self::from_provider(ObjectProvider::new(provider, qualifier))
+    }
+}
+
+impl<T: ?Sized + Send + Sync + 'static> Lazy<T> {
+    /// Lazy handle for a trait object `T`, e.g. `Lazy<dyn Repository>`.
+    pub fn new_dyn(provider: &BeanProviderRef, qualifier: Option<&str>) -> Self {
+        Self::from_provider(ObjectProvider::new_dyn(provider, qualifier))
+    }
+
+    pub fn from_provider(provider: ObjectProvider<T>) -> Self {
+        Lazy {
+            provider,
+            bean: OnceLock::new(),
+        }
+    }
+
+    /// Returns the bean, resolving it on the first call. A failed resolution is not cached.
+    pub fn get(&self) -> Result<Arc<T>, ConstructorError> {
+        if let Some(bean) = self.bean.get() {
+            return Ok(bean.clone());
+        }
+        let bean = self.provider.get()?;
+        Ok(self.bean.get_or_init(|| bean).clone())
+    }
+}
@@ -1,7 +1,17 @@
+mod aware;
+mod condition;
 mod error;
+mod event;
+mod key;
+mod lazy;
+mod lifecycle;
+mod resolve;
+mod type_info;
 
 use std::any::{Any, TypeId};
-use std::sync::Arc;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Weak};
 
 // --- Type Aliases ---
 /// A shared, thread-safe, dynamically-typed bean instance.
@@ -13,13 +23,33 @@ pub type BeanConstructorResult = Result<BeanInstance, ConstructorError>;
 /// A shared, thread-safe reference to a bean provider (typically the IoC container itself).
 pub type BeanProviderRef = Arc<dyn BeanProvider + Send + Sync>;
 
+/// A non-owning reference to a bean provider, for beans that hold on to the container without
+/// keeping it alive.
+pub type WeakBeanProviderRef = Weak<dyn BeanProvider + Send + Sync>;
+
 /// Function signature for a bean constructor.
 /// Takes a reference to the bean provider and returns a result containing the bean instance or an error.
 pub type BeanConstructor = fn(provider: BeanProviderRef) -> BeanConstructorResult;
 
+/// Future returned by an async bean constructor.
+pub type BeanConstructorFuture = Pin<Box<dyn Future<Output = BeanConstructorResult> + Send>>;
+
+/// Function signature for a bean constructor that must be awaited, e.g. one that connects to
+/// a database. Generated for `#[constructor]` impls whose `new` is an `async fn`.
+pub type AsyncBeanConstructor = fn(provider: BeanProviderRef) -> BeanConstructorFuture;
+
 /// Function signature for getting a TypeId.
 pub type TypeIdGetter = fn() -> TypeId;
 
+/// Function signature for getting a type's name, as returned by `std::any::type_name`.
+pub type TypeNameGetter = fn() -> &'static str;
+
+/// Function signature for converting a bean instance into a view of another type it provides.
+///
+/// Trait-object views are erased as `Arc<Arc<dyn Trait>>`, since `Arc<dyn Trait>` itself cannot
+/// be stored behind `dyn Any`. Returns `None` if the instance is not of the expected concrete type.
+pub type BeanCaster = fn(instance: BeanInstance) -> Option<BeanInstance>;
+
 // Forward declaration for the trait alias
 pub trait BeanProvider: Any + Send + Sync {
     /// Retrieves a bean instance by its TypeId.
@@ -27,21 +57,214 @@ pub trait BeanProvider: Any + Send + Sync {
     /// or if the container is not initialized.
     fn get_bean_by_typeid(&self, type_id: TypeId) -> Result<BeanInstance, ConstructorError>;
 
+    /// Retrieves the bean for `type_id` whose qualifiers (or bean name) include `qualifier`.
+    ///
+    /// The default matches no bean; providers that support qualifiers override it.
+    fn get_qualified_bean_by_typeid(
+        &self,
+        type_id: TypeId,
+        qualifier: &str,
+    ) -> Result<BeanInstance, ConstructorError> {
+        Err(ConstructorError::BeanNotFoundByQualifier {
+            bean_type: TypeInfo::unnamed(type_id),
+            qualifier: qualifier.to_string(),
+            suggestions: Suggestions::default(),
+        })
+    }
+
+    /// Retrieves every bean registered for `type_id` with its bean name, in bean order
+    /// (`#[order(n)]`, then bean name). Yields an empty list when there are none.
+    ///
+    /// The default reports the type as not found; providers that support collection lookups
+    /// override it.
+    fn get_beans_by_typeid(
+        &self,
+        type_id: TypeId,
+    ) -> Result<Vec<(String, BeanInstance)>, ConstructorError> {
+        Err(ConstructorError::BeanNotFoundByType {
+            bean_type: TypeInfo::unnamed(type_id),
+            suggestions: Suggestions::default(),
+        })
+    }
+
+    /// Retrieves a bean instance by its bean name.
+    ///
+    /// The default finds no bean; providers that know bean names override it.
+    fn get_bean_instance_by_name(&self, name: &str) -> Result<BeanInstance, ConstructorError> {
+        Err(ConstructorError::BeanNotFoundByName {
+            name: name.to_string(),
+            suggestions: Suggestions::default(),
+        })
+    }
+
     // Removed get_bean<T> due to dyn safety issues.
-    // Callers should use get_bean_by_typeid and downcast manually.
+    // Callers should use get_bean_by_typeid and downcast manually,
+    // or the typed helpers in the `resolve` module.
 
     /// Returns the provider as a `dyn Any` reference.
     fn as_any(&self) -> &dyn Any;
 }
+
 // --- Metadata Struct --- (Moved from metadata.rs for simplicity, could be separate)
 pub struct BeanDefinitionMetadata {
     pub bean_name: &'static str,
     pub bean_type_id: TypeIdGetter,
+    pub bean_type_name: TypeNameGetter,
     pub constructor: BeanConstructor,
+    /// Set when the bean's `#[constructor]` is an `async fn`; the container uses it instead of
+    /// `constructor`.
+    pub async_constructor: Option<AsyncBeanConstructor>,
+    /// Additional types (usually trait objects) the bean can be looked up as.
+    pub provides: &'static [ProvidedTypeMetadata],
+    /// Whether this bean wins when several beans match an unqualified lookup.
+    pub is_primary: bool,
+    /// Qualifiers that injection points can use to select this bean.
+    pub qualifiers: &'static [&'static str],
+    /// Scope name: `"singleton"`, `"prototype"`, or the name of a custom scope.
+    pub scope: &'static str,
+    /// Method marked `#[post_construct]`, run right after the bean is constructed.
+    pub post_construct: Option<LifecycleCallback>,
+    /// Method marked `#[pre_destroy]`, run when the container is closed.
+    pub pre_destroy: Option<LifecycleCallback>,
+    /// Skip eager instantiation at startup; the bean is created on first lookup.
+    pub lazy: bool,
+    /// Dependencies injected through `#[constructor]` parameters or `#[autowired]` fields.
+    pub dependencies: &'static [DependencyMetadata],
+    /// Position among beans of the same type in collection injection; lower comes first.
+    pub order: Option<i32>,
+    /// Set for `#[component(bean_name_aware)]` beans, which implement [`BeanNameAware`].
+    pub bean_name_aware: Option<BeanNameAwareCallback>,
+    /// Set for `#[component(container_aware)]` beans, which implement [`ContainerAware`].
+    pub container_aware: Option<ContainerAwareCallback>,
+    /// Conditions that must all hold for the bean to be registered.
+    pub conditions: &'static [ConditionMetadata],
+}
+
+/// Compile-time description of an additional type a bean is registered under.
+pub struct ProvidedTypeMetadata {
+    pub type_id: TypeIdGetter,
+    pub type_name: TypeNameGetter,
+    pub caster: BeanCaster,
+}
+
+/// Compile-time description of one injection point of a bean.
+pub struct DependencyMetadata {
+    /// The requested type: `T` for `Arc<T>`, possibly a trait object.
+    pub type_id: TypeIdGetter,
+    pub type_name: TypeNameGetter,
+    pub qualifier: Option<&'static str>,
+    /// `true` for `Option<Arc<T>>` injection points.
+    pub optional: bool,
+    /// `true` for `Vec<Arc<T>>` and `HashMap<String, Arc<T>>` injection points, which receive
+    /// every bean of the type.
+    pub collection: bool,
+    /// `true` for `Lazy<T>` and `ObjectProvider<T>` injection points, which resolve the bean
+    /// after construction and may therefore close a dependency cycle.
+    pub lazy: bool,
 }
 
 inventory::collect!(BeanDefinitionMetadata);
 
+/// Items referenced by code generated in `summer-macros`. Not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    use super::{
+        ApplicationEvent, AsyncBeanConstructor, Bean, BeanKey, BeanProviderRef, ConstructorError,
+        DependencyMetadata, LifecycleCallback,
+    };
+    use std::any::TypeId;
+    use std::sync::Arc;
+
+    /// Fallback construction strategy for components without a `#[constructor]` impl.
+    ///
+    /// Generated wrappers call `Type::__summer_construct(..)`. An inherent function of that
+    /// name (emitted by `#[constructor]`) shadows this trait method, so the `Default`
+    /// path is only taken when no explicit constructor exists.
+    pub trait DefaultConstructor: Default {
+        fn __summer_construct(_provider: &BeanProviderRef) -> Result<Self, ConstructorError> {
+            Ok(Self::default())
+        }
+    }
+
+    impl<T: Default> DefaultConstructor for T {}
+
+    /// Construction strategy of components with `#[autowired]` fields, implemented by
+    /// `#[component]`. A trait impl rather than inherent items, so that a conflicting
+    /// `#[constructor]` impl is reported by the component instead of as duplicate definitions.
+    pub trait FieldInjection: Sized {
+        const __SUMMER_DEPENDENCIES: &'static [DependencyMetadata];
+
+        fn __summer_construct(provider: &BeanProviderRef) -> Result<Self, ConstructorError>;
+    }
+
+    /// Fallback for components without a `#[constructor]` impl, which emits an inherent
+    /// associated const of the same name.
+    pub trait NoConstructorImpl {
+        const __SUMMER_HAS_CONSTRUCTOR: bool = false;
+    }
+
+    impl<T: ?Sized> NoConstructorImpl for T {}
+
+    /// Fallback lifecycle callbacks for components without `#[post_construct]`/`#[pre_destroy]`.
+    ///
+    /// Those attributes emit inherent associated consts of the same names, which shadow these.
+    pub trait NoLifecycleCallbacks {
+        const __SUMMER_POST_CONSTRUCT: Option<LifecycleCallback> = None;
+        const __SUMMER_PRE_DESTROY: Option<LifecycleCallback> = None;
+    }
+
+    impl<T: ?Sized> NoLifecycleCallbacks for T {}
+
+    /// Fallback for components without injection points; `#[constructor]` and `#[autowired]`
+    /// emit an inherent associated const of the same name listing their dependencies.
+    pub trait NoDeclaredDependencies {
+        const __SUMMER_DEPENDENCIES: &'static [DependencyMetadata] = &[];
+    }
+
+    impl<T: ?Sized> NoDeclaredDependencies for T {}
+
+    /// Fallback for components without an async `#[constructor]`, which emits an inherent
+    /// associated const of the same name.
+    pub trait NoAsyncConstructor {
+        const __SUMMER_ASYNC_CONSTRUCTOR: Option<AsyncBeanConstructor> = None;
+    }
+
+    impl<T: ?Sized> NoAsyncConstructor for T {}
+
+    /// `TypeId` getter used by `#[event_listener]`; the bound rejects parameters whose type
+    /// does not implement `ApplicationEvent`.
+    pub fn event_type_id<E: ApplicationEvent>() -> TypeId {
+        TypeId::of::<E>()
+    }
+
+    /// The bean name behind a `#[qualifier(Component::BEAN)]` key. `_upcast` is `|bean| bean`,
+    /// which only compiles if the keyed component can be injected as `Arc<T>`.
+    pub fn qualifier_key<T: ?Sized, K: Bean>(
+        key: BeanKey<K>,
+        _upcast: fn(Arc<K>) -> Arc<T>,
+    ) -> &'static str {
+        key.name()
+    }
+}
+
 // --- Public Exports ---
+pub use aware::{
+    notify_bean_name, notify_container, BeanNameAware, BeanNameAwareCallback, ContainerAware,
+    ContainerAwareCallback,
+};
+pub use condition::ConditionMetadata;
+pub use error::ConstructorError;
+pub use event::{
+    ApplicationEvent, ApplicationListener, EventFuture, EventInstance, EventListenerCallback,
+    EventListenerMetadata,
+};
 pub use inventory;
-pub use error::ConstructorError;
\ No newline at end of file
+pub use key::{Bean, BeanKey};
+pub use lazy::{Lazy, ObjectProvider};
+pub use lifecycle::{IntoLifecycleResult, LifecycleCallback, LifecycleFuture};
+pub use resolve::{
+    optional_bean, resolve_bean, resolve_bean_dyn, resolve_bean_map, resolve_bean_map_dyn,
+    resolve_beans, resolve_beans_dyn, resolve_by_key, resolve_optional_bean,
+    resolve_optional_bean_dyn, resolve_qualified_bean, resolve_qualified_bean_dyn,
+};
+pub use type_info::{Suggestions, TypeInfo};
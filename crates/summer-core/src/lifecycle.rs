@@ -0,0 +1,36 @@
+//! Lifecycle callbacks generated by `#[post_construct]` and `#[pre_destroy]`.
+
+use crate::{BeanInstance, ConstructorError};
+use std::error::Error as StdError;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Future returned by an async lifecycle callback.
+pub type LifecycleFuture = Pin<Box<dyn Future<Output = Result<(), ConstructorError>> + Send>>;
+
+/// A type-erased lifecycle callback bound to one bean type.
+#[derive(Clone, Copy)]
+pub enum LifecycleCallback {
+    /// Calls a plain method on the bean.
+    Sync(fn(instance: &BeanInstance) -> Result<(), ConstructorError>),
+    /// Calls an `async fn` on the bean; the container awaits the returned future.
+    Async(fn(instance: BeanInstance) -> LifecycleFuture),
+}
+
+/// Converts the return value of a lifecycle method (`()` or `Result<(), E>`) into the
+/// container's error type, keeping `E` as the error source.
+pub trait IntoLifecycleResult {
+    fn into_lifecycle_result(self) -> Result<(), ConstructorError>;
+}
+
+impl IntoLifecycleResult for () {
+    fn into_lifecycle_result(self) -> Result<(), ConstructorError> {
+        Ok(())
+    }
+}
+
+impl<E: Into<Box<dyn StdError + Send + Sync>>> IntoLifecycleResult for Result<(), E> {
+    fn into_lifecycle_result(self) -> Result<(), ConstructorError> {
+        self.map_err(|e| ConstructorError::Other(e.into()))
+    }
+}
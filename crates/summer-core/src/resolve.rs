@@ -0,0 +1,157 @@
+//! Typed lookup helpers on top of [`BeanProvider`].
+//!
+//! `BeanProvider` is object safe and therefore only deals in `TypeId`s and erased
+//! `BeanInstance`s. These functions add the downcasting, and are what the constructor
+//! wrappers generated by `summer-macros` call for each injected dependency.
+
+use crate::{Bean, BeanInstance, BeanKey, BeanProvider, ConstructorError, TypeInfo};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Resolves the bean registered for type `T` and downcasts it to `Arc<T>`.
+///
+/// This is the typed counterpart of [`BeanProvider::get_bean_by_typeid`].
+pub fn resolve_bean<T: Any + Send + Sync>(
+    provider: &dyn BeanProvider,
+) -> Result<Arc<T>, ConstructorError> {
+    let instance = provider
+        .get_bean_by_typeid(TypeId::of::<T>())
+        .map_err(|e| e.with_type_name(TypeInfo::of::<T>()))?;
+    downcast_bean(instance)
+}
+
+/// Resolves the bean of the component `T` by its key, e.g. `UserService::BEAN`.
+pub fn resolve_by_key<T: Bean>(
+    provider: &dyn BeanProvider,
+    key: BeanKey<T>,
+) -> Result<Arc<T>, ConstructorError> {
+    downcast_bean(provider.get_bean_instance_by_name(key.name())?)
+}
+
+/// Resolves the bean of type `T` selected by `qualifier`.
+pub fn resolve_qualified_bean<T: Any + Send + Sync>(
+    provider: &dyn BeanProvider,
+    qualifier: &str,
+) -> Result<Arc<T>, ConstructorError> {
+    let instance = provider
+        .get_qualified_bean_by_typeid(TypeId::of::<T>(), qualifier)
+        .map_err(|e| e.with_type_name(TypeInfo::of::<T>()))?;
+    downcast_bean(instance)
+}
+
+/// Resolves the bean registered as provider of the trait object `T` (e.g. `dyn Repository`).
+///
+/// The container hands out trait-object views erased as `Arc<Arc<T>>`; see [`crate::BeanCaster`].
+pub fn resolve_bean_dyn<T: ?Sized + Send + Sync + 'static>(
+    provider: &dyn BeanProvider,
+) -> Result<Arc<T>, ConstructorError> {
+    let view = provider
+        .get_bean_by_typeid(TypeId::of::<T>())
+        .map_err(|e| e.with_type_name(TypeInfo::of::<T>()))?;
+    downcast_view(view)
+}
+
+/// Resolves the provider of the trait object `T` selected by `qualifier`.
+pub fn resolve_qualified_bean_dyn<T: ?Sized + Send + Sync + 'static>(
+    provider: &dyn BeanProvider,
+    qualifier: &str,
+) -> Result<Arc<T>, ConstructorError> {
+    let view = provider
+        .get_qualified_bean_by_typeid(TypeId::of::<T>(), qualifier)
+        .map_err(|e| e.with_type_name(TypeInfo::of::<T>()))?;
+    downcast_view(view)
+}
+
+/// Like [`resolve_bean`], but yields `None` when no bean is registered for type `T`.
+///
+/// Any other failure (ambiguity, a broken transitive dependency, ...) is still an error.
+pub fn resolve_optional_bean<T: Any + Send + Sync>(
+    provider: &dyn BeanProvider,
+) -> Result<Option<Arc<T>>, ConstructorError> {
+    optional_bean(resolve_bean::<T>(provider))
+}
+
+/// Like [`resolve_bean_dyn`], but yields `None` when no bean provides the trait object `T`.
+pub fn resolve_optional_bean_dyn<T: ?Sized + Send + Sync + 'static>(
+    provider: &dyn BeanProvider,
+) -> Result<Option<Arc<T>>, ConstructorError> {
+    optional_bean(resolve_bean_dyn::<T>(provider))
+}
+
+/// Resolves every bean registered for type `T`, in bean order.
+///
+/// This is the typed counterpart of [`BeanProvider::get_beans_by_typeid`].
+pub fn resolve_beans<T: Any + Send + Sync>(
+    provider: &dyn BeanProvider,
+) -> Result<Vec<Arc<T>>, ConstructorError> {
+    provider
+        .get_beans_by_typeid(TypeId::of::<T>())
+        .map_err(|e| e.with_type_name(TypeInfo::of::<T>()))?
+        .into_iter()
+        .map(|(_, instance)| downcast_bean(instance))
+        .collect()
+}
+
+/// Resolves every provider of the trait object `T`, in bean order.
+pub fn resolve_beans_dyn<T: ?Sized + Send + Sync + 'static>(
+    provider: &dyn BeanProvider,
+) -> Result<Vec<Arc<T>>, ConstructorError> {
+    provider
+        .get_beans_by_typeid(TypeId::of::<T>())
+        .map_err(|e| e.with_type_name(TypeInfo::of::<T>()))?
+        .into_iter()
+        .map(|(_, instance)| downcast_view(instance))
+        .collect()
+}
+
+/// Resolves every bean registered for type `T`, keyed by bean name.
+pub fn resolve_bean_map<T: Any + Send + Sync>(
+    provider: &dyn BeanProvider,
+) -> Result<HashMap<String, Arc<T>>, ConstructorError> {
+    provider
+        .get_beans_by_typeid(TypeId::of::<T>())
+        .map_err(|e| e.with_type_name(TypeInfo::of::<T>()))?
+        .into_iter()
+        .map(|(name, instance)| Ok((name, downcast_bean(instance)?)))
+        .collect()
+}
+
+/// Resolves every provider of the trait object `T`, keyed by bean name.
+pub fn resolve_bean_map_dyn<T: ?Sized + Send + Sync + 'static>(
+    provider: &dyn BeanProvider,
+) -> Result<HashMap<String, Arc<T>>, ConstructorError> {
+    provider
+        .get_beans_by_typeid(TypeId::of::<T>())
+        .map_err(|e| e.with_type_name(TypeInfo::of::<T>()))?
+        .into_iter()
+        .map(|(name, instance)| Ok((name, downcast_view(instance)?)))
+        .collect()
+}
+
+/// Turns a "no matching bean" lookup failure into `Ok(None)`; other errors are kept.
+pub fn optional_bean<T: ?Sized>(
+    result: Result<Arc<T>, ConstructorError>,
+) -> Result<Option<Arc<T>>, ConstructorError> {
+    match result {
+        Ok(bean) => Ok(Some(bean)),
+        Err(ConstructorError::BeanNotFoundByType { .. })
+        | Err(ConstructorError::BeanNotFoundByQualifier { .. }) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+fn downcast_bean<T: Any + Send + Sync>(instance: BeanInstance) -> Result<Arc<T>, ConstructorError> {
+    instance
+        .downcast::<T>()
+        .map_err(|_| ConstructorError::TypeMismatch(TypeInfo::of::<T>()))
+}
+
+fn downcast_view<T: ?Sized + Send + Sync + 'static>(
+    instance: BeanInstance,
+) -> Result<Arc<T>, ConstructorError> {
+    instance
+        .downcast::<Arc<T>>()
+        .map(|view| Arc::clone(&*view))
+        .map_err(|_| ConstructorError::TypeMismatch(TypeInfo::of::<T>()))
+}
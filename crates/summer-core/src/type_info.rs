@@ -0,0 +1,125 @@
+//! Type identities that remember their Rust type path, so errors can name the types involved
+//! instead of printing an opaque `TypeId`.
+
+use std::any::TypeId;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// A `TypeId` together with the type's path as returned by `std::any::type_name`, when known.
+///
+/// Equality and hashing only consider the `TypeId`. Formatting (both `Display` and `Debug`)
+/// prints the type path, or the raw `TypeId` for types whose name was never recorded.
+#[derive(Clone, Copy)]
+pub struct TypeInfo {
+    id: TypeId,
+    name: Option<&'static str>,
+}
+
+impl TypeInfo {
+    pub fn of<T: ?Sized + 'static>() -> Self {
+        TypeInfo {
+            id: TypeId::of::<T>(),
+            name: Some(std::any::type_name::<T>()),
+        }
+    }
+
+    pub fn new(id: TypeId, name: &'static str) -> Self {
+        TypeInfo {
+            id,
+            name: Some(name),
+        }
+    }
+
+    /// A type known only by its `TypeId`, e.g. one requested through `BeanProvider`.
+    pub fn unnamed(id: TypeId) -> Self {
+        TypeInfo { id, name: None }
+    }
+
+    pub fn id(&self) -> TypeId {
+        self.id
+    }
+
+    pub fn name(&self) -> Option<&'static str> {
+        self.name
+    }
+
+    /// Takes the name of `other` if it describes the same type and this one has no name.
+    pub fn or_named(self, other: TypeInfo) -> Self {
+        match self.name {
+            None if self.id == other.id => other,
+            _ => self,
+        }
+    }
+}
+
+impl PartialEq for TypeInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for TypeInfo {}
+
+impl PartialEq<TypeId> for TypeInfo {
+    fn eq(&self, other: &TypeId) -> bool {
+        self.id == *other
+    }
+}
+
+impl Hash for TypeInfo {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl fmt::Display for TypeInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{:?}", self.id),
+        }
+    }
+}
+
+impl fmt::Debug for TypeInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// "Did you mean" hints attached to lookup errors: similar bean names, or beans of similar
+/// types. Renders as nothing when empty.
+///
+/// Boxed as a slice to keep the lookup error variants small.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Suggestions(Box<[String]>);
+
+impl Suggestions {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[String] {
+        &self.0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+}
+
+impl From<Vec<String>> for Suggestions {
+    fn from(suggestions: Vec<String>) -> Self {
+        Suggestions(suggestions.into_boxed_slice())
+    }
+}
+
+impl fmt::Display for Suggestions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.as_slice() {
+            [] => Ok(()),
+            [single] => write!(f, " (did you mean {}?)", single),
+            [rest @ .., last] => write!(f, " (did you mean {} or {}?)", rest.join(", "), last),
+        }
+    }
+}
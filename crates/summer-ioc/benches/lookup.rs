@@ -0,0 +1,113 @@
+//! Singleton lookups on a regular and a frozen container, alone and from several threads.
+//!
+//! Run with `cargo bench -p summer-ioc --bench lookup`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::any::TypeId;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use summer_ioc::{BeanDefinition, IocContainer};
+
+trait Greeter: Send + Sync {
+    fn greet(&self) -> &'static str;
+}
+
+struct EnglishGreeter;
+
+impl Greeter for EnglishGreeter {
+    fn greet(&self) -> &'static str {
+        "hello"
+    }
+}
+
+struct Config {
+    port: u16,
+}
+
+struct Filler(#[allow(dead_code)] u16);
+
+fn container(frozen: bool) -> Arc<IocContainer> {
+    let container = IocContainer::new();
+    container
+        .register_singleton("config", Arc::new(Config { port: 8080 }))
+        .unwrap();
+    container
+        .register_bean_definition(
+            BeanDefinition::for_instance("greeter", Arc::new(EnglishGreeter)).with_provided_type(
+                TypeId::of::<dyn Greeter>(),
+                |instance| {
+                    let greeter = instance.downcast::<EnglishGreeter>().ok()?;
+                    let view: Arc<dyn Greeter> = greeter;
+                    Some(Arc::new(view))
+                },
+            ),
+        )
+        .unwrap();
+    // Unrelated beans, so lookups do not run against a trivially small registry.
+    for i in 0..100u16 {
+        container
+            .register_bean_definition(BeanDefinition::for_factory(
+                format!("filler{}", i),
+                move |_| Ok(Filler(i)),
+            ))
+            .unwrap();
+    }
+    container.initialize().unwrap();
+    if frozen {
+        container.freeze().unwrap();
+    }
+    container
+}
+
+fn single_thread(c: &mut Criterion) {
+    let mut group = c.benchmark_group("single_thread");
+    for frozen in [false, true] {
+        let container = container(frozen);
+        let mode = if frozen { "frozen" } else { "unfrozen" };
+        group.bench_function(BenchmarkId::new("get_bean", mode), |b| {
+            b.iter(|| black_box(container.get_bean::<Config>().unwrap().port))
+        });
+        group.bench_function(BenchmarkId::new("get_bean_dyn", mode), |b| {
+            b.iter(|| black_box(container.get_bean_dyn::<dyn Greeter>().unwrap().greet()))
+        });
+        group.bench_function(BenchmarkId::new("get_bean_by_name", mode), |b| {
+            b.iter(|| black_box(container.get_bean_by_name::<Config>("config").unwrap().port))
+        });
+    }
+    group.finish();
+}
+
+/// Every thread performs `iters` lookups; the measured time is the wall time until all of them
+/// are done, so lower means more lookups per second under contention.
+fn contended(c: &mut Criterion) {
+    let mut group = c.benchmark_group("contended_get_bean");
+    for threads in [2, 8] {
+        for frozen in [false, true] {
+            let container = container(frozen);
+            let mode = if frozen { "frozen" } else { "unfrozen" };
+            group.bench_with_input(BenchmarkId::new(mode, threads), &threads, |b, &threads| {
+                b.iter_custom(|iters| {
+                    let start = Instant::now();
+                    std::thread::scope(|scope| {
+                        for _ in 0..threads {
+                            scope.spawn(|| {
+                                for _ in 0..iters {
+                                    black_box(container.get_bean::<Config>().unwrap().port);
+                                }
+                            });
+                        }
+                    });
+                    start.elapsed()
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().measurement_time(Duration::from_secs(3));
+    targets = single_thread, contended
+}
+criterion_main!(benches);
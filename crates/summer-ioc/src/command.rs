@@ -0,0 +1,107 @@
+//! Shutdown commands (command pattern) collected while singletons are created and
+//! executed in reverse order when the container is closed.
+
+use crate::error::IocError;
+use futures::FutureExt;
+use std::panic::{self, AssertUnwindSafe};
+use summer_core::{BeanInstance, ConstructorError, LifecycleCallback};
+
+/// Invokes a bean's `#[pre_destroy]` callback.
+pub(crate) struct PreDestroyCommand {
+    bean_name: String,
+    instance: BeanInstance,
+    callback: LifecycleCallback,
+}
+
+impl PreDestroyCommand {
+    pub(crate) fn new(
+        bean_name: String,
+        instance: BeanInstance,
+        callback: LifecycleCallback,
+    ) -> Self {
+        PreDestroyCommand {
+            bean_name,
+            instance,
+            callback,
+        }
+    }
+
+    pub(crate) fn bean_name(&self) -> &str {
+        &self.bean_name
+    }
+
+    /// Runs a synchronous callback. Async callbacks are not run but reported as errors, since
+    /// only [`PreDestroyCommand::execute_async`] can await them. Panics are caught and reported
+    /// as errors.
+    pub(crate) fn execute(&self) -> Result<(), IocError> {
+        let result = match self.callback {
+            LifecycleCallback::Sync(callback) => {
+                panic::catch_unwind(AssertUnwindSafe(|| callback(&self.instance)))
+            }
+            LifecycleCallback::Async(_) => Ok(Err(ConstructorError::BaseMsgError(
+                "async callback cannot run when the container is closed synchronously; \
+                 use close_async"
+                    .to_string(),
+            ))),
+        };
+        self.to_ioc_result(result)
+    }
+
+    /// Runs the callback, awaiting it if it is async. Panics are caught and reported as errors.
+    pub(crate) async fn execute_async(&self) -> Result<(), IocError> {
+        let result = match self.callback {
+            LifecycleCallback::Sync(callback) => {
+                panic::catch_unwind(AssertUnwindSafe(|| callback(&self.instance)))
+            }
+            LifecycleCallback::Async(callback) => {
+                AssertUnwindSafe(callback(self.instance.clone()))
+                    .catch_unwind()
+                    .await
+            }
+        };
+        self.to_ioc_result(result)
+    }
+
+    fn to_ioc_result(
+        &self,
+        result: std::thread::Result<Result<(), ConstructorError>>,
+    ) -> Result<(), IocError> {
+        let source = match result {
+            Ok(Ok(())) => return Ok(()),
+            Ok(Err(e)) => e,
+            Err(_) => ConstructorError::BaseMsgError("callback panicked".to_string()),
+        };
+        Err(IocError::LifecycleCallbackError {
+            bean_name: self.bean_name.clone(),
+            callback: "pre_destroy",
+            source,
+        })
+    }
+}
+
+/// Invokes a `#[post_construct]` callback from synchronous code. Async callbacks are rejected
+/// rather than blocked on, which would stall the runtime a caller may be running on.
+pub(crate) fn invoke_lifecycle_callback(
+    callback: LifecycleCallback,
+    instance: &BeanInstance,
+) -> Result<(), ConstructorError> {
+    match callback {
+        LifecycleCallback::Sync(callback) => callback(instance),
+        LifecycleCallback::Async(_) => Err(ConstructorError::BaseMsgError(
+            "async callback cannot run on a synchronous lookup; \
+             create the bean with initialize_async or get_bean_async"
+                .to_string(),
+        )),
+    }
+}
+
+/// Async counterpart of [`invoke_lifecycle_callback`], awaiting async callbacks.
+pub(crate) async fn invoke_lifecycle_callback_async(
+    callback: LifecycleCallback,
+    instance: &BeanInstance,
+) -> Result<(), ConstructorError> {
+    match callback {
+        LifecycleCallback::Sync(callback) => callback(instance),
+        LifecycleCallback::Async(callback) => callback(instance.clone()).await,
+    }
+}
@@ -0,0 +1,102 @@
+use serde::Serialize;
+use std::any::TypeId;
+use std::collections::{HashMap, HashSet};
+use summer_core::ConditionMetadata;
+
+/// A condition that must hold for a bean to be registered (like Spring's `@Conditional`).
+/// Conditions are evaluated by every `IocContainer::initialize`.
+#[derive(Clone, Debug)]
+pub enum BeanCondition {
+    /// The property `name` is set and equals `having_value`, or is not `"false"` when no value
+    /// is given. A missing property matches only if `match_if_missing` is set.
+    OnProperty {
+        name: String,
+        having_value: Option<String>,
+        match_if_missing: bool,
+    },
+    /// At least one of the profiles is active; `"!name"` matches when `name` is not active.
+    Profile(Vec<String>),
+    /// No other bean is registered under `type_id`.
+    OnMissingBean { type_id: TypeId, type_name: String },
+    /// The cargo feature was enabled for the crate declaring the bean.
+    OnFeature { feature: String, enabled: bool },
+}
+
+impl From<&ConditionMetadata> for BeanCondition {
+    fn from(metadata: &ConditionMetadata) -> Self {
+        match *metadata {
+            ConditionMetadata::OnProperty {
+                name,
+                having_value,
+                match_if_missing,
+            } => BeanCondition::OnProperty {
+                name: name.to_string(),
+                having_value: having_value.map(str::to_string),
+                match_if_missing,
+            },
+            ConditionMetadata::Profile(profiles) => {
+                BeanCondition::Profile(profiles.iter().map(|p| p.to_string()).collect())
+            }
+            ConditionMetadata::OnMissingBean { type_id, type_name } => {
+                BeanCondition::OnMissingBean {
+                    type_id: type_id(),
+                    type_name: type_name().to_string(),
+                }
+            }
+            ConditionMetadata::OnFeature { feature, enabled } => BeanCondition::OnFeature {
+                feature: feature.to_string(),
+                enabled,
+            },
+        }
+    }
+}
+
+impl BeanCondition {
+    /// Evaluates every condition except `OnMissingBean`, which depends on the other beans.
+    /// Returns why the condition does not hold, or `None` if it does (or is `OnMissingBean`).
+    pub(crate) fn check_environment(
+        &self,
+        properties: &HashMap<String, String>,
+        active_profiles: &HashSet<String>,
+    ) -> Option<String> {
+        match self {
+            BeanCondition::OnProperty {
+                name,
+                having_value,
+                match_if_missing,
+            } => match (properties.get(name), having_value) {
+                (None, _) if *match_if_missing => None,
+                (None, _) => Some(format!("property '{}' is not set", name)),
+                (Some(value), Some(expected)) if value != expected => Some(format!(
+                    "property '{}' is '{}', expected '{}'",
+                    name, value, expected
+                )),
+                (Some(value), None) if value.eq_ignore_ascii_case("false") => {
+                    Some(format!("property '{}' is 'false'", name))
+                }
+                _ => None,
+            },
+            BeanCondition::Profile(profiles) => {
+                let matches = profiles
+                    .iter()
+                    .any(|profile| match profile.strip_prefix('!') {
+                        Some(inactive) => !active_profiles.contains(inactive),
+                        None => active_profiles.contains(profile),
+                    });
+                (!matches).then(|| format!("no matching profile in [{}]", profiles.join(", ")))
+            }
+            BeanCondition::OnFeature { feature, enabled } => {
+                (!enabled).then(|| format!("cargo feature '{}' is not enabled", feature))
+            }
+            BeanCondition::OnMissingBean { .. } => None,
+        }
+    }
+}
+
+/// A bean that was not registered because one of its conditions did not hold.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SkippedBean {
+    pub bean_name: String,
+    /// The first condition that failed, e.g. `property 'cache.enabled' is not set`.
+    pub reason: String,
+}
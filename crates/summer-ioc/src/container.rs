@@ -1,27 +1,142 @@
 // Import necessary items from summer_core, including the new aliases
-use crate::definition::BeanDefinition;
+use crate::command::{
+    invoke_lifecycle_callback, invoke_lifecycle_callback_async, PreDestroyCommand,
+};
+use crate::condition::{BeanCondition, SkippedBean};
+use crate::definition::{BeanDefinition, BeanDependency, BeanOverridePolicy, UNKNOWN_TYPE_NAME};
 use crate::error::IocError;
-use parking_lot::RwLock;
+use crate::event::{ApplicationEventMulticaster, BeanCreated, ContextClosing, ContextRefreshed};
+use crate::frozen::FrozenBeans;
+use crate::graph::{DependencyEdge, DependencyGraph, UnresolvedDependency};
+use crate::introspection::{BeanDescriptor, ContainerReport};
+use crate::processor::BeanPostProcessor;
+use crate::scope::{BeanScope, Scope};
+use crate::suggest::{short_type_name, similar};
+use arc_swap::ArcSwapOption;
+use futures::future::BoxFuture;
+use parking_lot::{Mutex, RwLock};
 use std::any::{Any, TypeId};
-use std::collections::{HashMap, HashSet};
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::sync::Arc;
+use std::thread::ThreadId;
+use std::time::SystemTime;
 use summer_core::{
-    BeanConstructorResult, // Result<BeanInstance, ConstructorError>
+    ApplicationEvent,
+    ApplicationListener,
+    Bean,
+    BeanConstructorResult,
     BeanDefinitionMetadata,
-    BeanInstance,    // Arc<dyn Any + Send + Sync>
+    BeanInstance, // Arc<dyn Any + Send + Sync>
+    BeanKey,
     BeanProvider,    // The trait
     BeanProviderRef, // Arc<dyn BeanProvider + Send + Sync>
     ConstructorError,
+    EventListenerMetadata,
+    Suggestions,
+    TypeInfo,
 };
 
+thread_local! {
+    // Beans currently being created on this thread, tagged with the owning container's address,
+    // in the order creation started. Constructors resolve their dependencies synchronously on
+    // the calling thread, so this is exactly the current resolution chain.
+    static CREATION_CHAIN: RefCell<Vec<(usize, String)>> = const { RefCell::new(Vec::new()) };
+    // Set when this thread backed off instead of waiting for another thread's creation lock
+    // (see `create_singleton`): the owning container's address and the lock to wait for once
+    // the outermost creation has released this thread's locks, before it is retried.
+    static CREATION_RETRY: RefCell<Option<(usize, Arc<Mutex<()>>)>> = const { RefCell::new(None) };
+}
+
+/// The per-singleton creation locks of a container, with who holds and who waits for them, so
+/// that a wait that would deadlock is detected before blocking.
+#[derive(Default)]
+struct CreationLocks {
+    locks: HashMap<String, Arc<Mutex<()>>>,
+    owners: HashMap<String, ThreadId>,
+    waiting: HashMap<ThreadId, String>,
+}
+
+impl CreationLocks {
+    /// Whether `thread` waiting for the lock of `name` would close a cycle of threads each
+    /// waiting for a lock held by the next one.
+    fn would_deadlock(&self, thread: ThreadId, name: &str) -> bool {
+        let mut owner = self.owners.get(name);
+        // Each thread waits for at most one lock, so the walk visits every thread at most once.
+        for _ in 0..=self.waiting.len() {
+            match owner {
+                Some(holder) if *holder == thread => return true,
+                Some(holder) => {
+                    owner = self
+                        .waiting
+                        .get(holder)
+                        .and_then(|wanted| self.owners.get(wanted))
+                }
+                None => return false,
+            }
+        }
+        false
+    }
+}
+
+/// Records the current thread as the owner of a singleton's creation lock, until dropped.
+struct CreationOwnership<'a> {
+    locks: &'a Mutex<CreationLocks>,
+    name: &'a str,
+}
+
+impl Drop for CreationOwnership<'_> {
+    fn drop(&mut self) {
+        self.locks.lock().owners.remove(self.name);
+    }
+}
+
 /// The core Inversion of Control (IoC) container.
 pub struct IocContainer {
     definitions: RwLock<HashMap<String, BeanDefinition>>,
     // Use the BeanInstance alias from summer_core
     singleton_instances: RwLock<HashMap<String, BeanInstance>>,
+    // When each cached singleton was created, for introspection
+    singleton_created_at: RwLock<HashMap<String, SystemTime>>,
     beans_by_type: RwLock<HashMap<TypeId, Vec<String>>>,
+    // Rust type paths of the bean, provided and dependency types seen so far, for error messages
+    type_names: RwLock<HashMap<TypeId, &'static str>>,
     initialized: RwLock<bool>,
-    currently_in_creation: RwLock<HashSet<String>>,
+    // Set by `freeze`: lock-free lookups of the existing singletons
+    frozen: ArcSwapOption<FrozenBeans>,
+    // Instantiate non-lazy singletons during `initialize` (on by default)
+    eager_init: RwLock<bool>,
+    // How many singletons `initialize_async` may create at once (1 creates them one by one)
+    startup_parallelism: RwLock<usize>,
+    // One lock per singleton, held while it is constructed so it is built exactly once
+    creation_locks: Mutex<CreationLocks>,
+    // Held across the await of an async constructor, so async creation also runs exactly once
+    async_creation_locks: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+    // Custom scopes by name; singleton and prototype are handled by the container itself
+    scopes: RwLock<HashMap<String, Arc<dyn Scope>>>,
+    // Applied when a definition is registered under a name that is already taken
+    override_policy: RwLock<BeanOverridePolicy>,
+    // Beans swapped with `replace_bean_definition`; their `#[component]`s are not registered
+    replaced_beans: RwLock<HashSet<String>>,
+    // Definitions registered from `#[component]`s, dropped whenever the container is reset so
+    // that the next `initialize` can register them again
+    discovered_beans: Mutex<Vec<String>>,
+    // Consulted by bean conditions in `initialize`
+    properties: RwLock<HashMap<String, String>>,
+    active_profiles: RwLock<HashSet<String>>,
+    // Beans dropped by `initialize` because one of their conditions failed
+    skipped_beans: RwLock<Vec<SkippedBean>>,
+    // Programmatic definitions among the skipped beans, registered again when the container is
+    // reset so that the next `initialize` evaluates their conditions afresh
+    skipped_definitions: Mutex<Vec<BeanDefinition>>,
+    // Listeners for published events, including the `#[event_listener]` methods of singletons
+    event_multicaster: ApplicationEventMulticaster,
+    // Applied to every bean as it is created, in registration order
+    post_processors: RwLock<Vec<Arc<dyn BeanPostProcessor>>>,
+    // `#[pre_destroy]` commands in singleton creation order, executed in reverse on close
+    shutdown_commands: Mutex<Vec<PreDestroyCommand>>,
+    // Lookups that find no local bean fall back to the parent container, if any
+    parent: Option<BeanProviderRef>,
     // Store self as Arc<Self> to pass to constructors
     // Use BeanProviderRef for the type
     self_arc: RwLock<Option<BeanProviderRef>>,
@@ -31,40 +146,31 @@ pub struct IocContainer {
 impl BeanProvider for IocContainer {
     // Use BeanInstance alias in the return type
     fn get_bean_by_typeid(&self, type_id: TypeId) -> Result<BeanInstance, ConstructorError> {
-        // Ensure container is initialized before attempting to get beans.
+        self.provide_bean(type_id, None)
+    }
+
+    fn get_qualified_bean_by_typeid(
+        &self,
+        type_id: TypeId,
+        qualifier: &str,
+    ) -> Result<BeanInstance, ConstructorError> {
+        self.provide_bean(type_id, Some(qualifier))
+    }
+
+    fn get_beans_by_typeid(
+        &self,
+        type_id: TypeId,
+    ) -> Result<Vec<(String, BeanInstance)>, ConstructorError> {
         if !*self.initialized.read() {
-            // Return ConstructorError, which is Box<dyn Error>
             return Err(ConstructorError::ContainerNotInitialized);
         }
+        self.find_beans(type_id)
+            .map_err(|e| into_dependency_error(self.type_info(type_id), e))
+    }
 
-        let bean_names = {
-            let beans_by_type_guard = self.beans_by_type.read();
-            // Find the bean names associated with the TypeId
-            beans_by_type_guard.get(&type_id).cloned()
-        };
-
-        match bean_names {
-            Some(names) => {
-                if names.len() == 1 {
-                    // Exactly one bean found for this type. Attempt to retrieve it.
-                    // Use the internal method that returns Result<BeanInstance, IocError>
-                    self.get_bean_by_name_any(&names[0])
-                        .map_err(|_| ConstructorError::BaseError) // Map IocError to ConstructorError
-                } else if names.is_empty() {
-                    // This case should ideally not happen if beans_by_type is consistent,
-                    // but handle it defensively.
-                    Err(ConstructorError::BeanNotFoundByType(type_id))
-                } else {
-                    // Multiple beans found for the same type. This is an error unless
-                    // mechanisms like @Primary or @Qualifier are implemented.
-                    Err(ConstructorError::MultipleBeansFound(type_id))
-                }
-            }
-            None => {
-                // No bean definition found for this type ID.
-                Err(ConstructorError::BeanNotFoundByType(type_id))
-            }
-        }
+    fn get_bean_instance_by_name(&self, name: &str) -> Result<BeanInstance, ConstructorError> {
+        self.get_bean_by_name_any(name)
+            .map_err(into_constructor_error)
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -78,12 +184,43 @@ impl IocContainer {
     /// Creates a new, empty IocContainer wrapped in an Arc.
     // The return type Arc<Self> implicitly works with BeanProviderRef due to the blanket impl
     pub fn new() -> Arc<Self> {
+        Self::with_parent(None)
+    }
+
+    /// Creates a child container. Lookups that match no local bean are delegated to `parent`,
+    /// local beans shadow the parent's, and closing the child leaves the parent's beans intact.
+    ///
+    /// A child only holds beans registered on it programmatically; `#[component]` beans are
+    /// discovered by the root container.
+    pub fn new_child(parent: BeanProviderRef) -> Arc<Self> {
+        Self::with_parent(Some(parent))
+    }
+
+    fn with_parent(parent: Option<BeanProviderRef>) -> Arc<Self> {
         let container = Arc::new(IocContainer {
             definitions: RwLock::new(HashMap::new()),
             singleton_instances: RwLock::new(HashMap::new()),
+            singleton_created_at: RwLock::new(HashMap::new()),
             beans_by_type: RwLock::new(HashMap::new()),
+            type_names: RwLock::new(HashMap::new()),
             initialized: RwLock::new(false),
-            currently_in_creation: RwLock::new(HashSet::new()),
+            frozen: ArcSwapOption::empty(),
+            eager_init: RwLock::new(true),
+            startup_parallelism: RwLock::new(1),
+            creation_locks: Mutex::new(CreationLocks::default()),
+            async_creation_locks: Mutex::new(HashMap::new()),
+            scopes: RwLock::new(HashMap::new()),
+            override_policy: RwLock::new(BeanOverridePolicy::default()),
+            replaced_beans: RwLock::new(HashSet::new()),
+            discovered_beans: Mutex::new(Vec::new()),
+            properties: RwLock::new(HashMap::new()),
+            active_profiles: RwLock::new(HashSet::new()),
+            skipped_beans: RwLock::new(Vec::new()),
+            skipped_definitions: Mutex::new(Vec::new()),
+            event_multicaster: ApplicationEventMulticaster::default(),
+            post_processors: RwLock::new(Vec::new()),
+            shutdown_commands: Mutex::new(Vec::new()),
+            parent,
             self_arc: RwLock::new(None), // Initialize as None
         });
         // Store the Arc<Self> within the container itself, it acts as BeanProviderRef
@@ -91,66 +228,644 @@ impl IocContainer {
         container
     }
 
-    /// Initializes the container by collecting bean metadata.
+    /// Initializes the container by collecting bean metadata. On failure, including a
+    /// `ContextRefreshed` listener returning an error, the container is left uninitialized, so
+    /// `initialize` can be retried once the cause is fixed.
     // Takes Arc<Self> which acts as BeanProviderRef
     pub fn initialize(self: &Arc<Self>) -> Result<(), IocError> {
-        let mut initialized_guard = self.initialized.write();
-        if *initialized_guard {
+        if !self.register_discovered_beans()? {
             // Already initialized, do nothing.
             return Ok(());
         }
+        if *self.eager_init.read() {
+            self.preinstantiate_singletons()?;
+        }
+        log::info!("Summer IOC container initialized successfully."); // Added logging
+        if let Err(e) = self.publish_event(ContextRefreshed) {
+            // A failed refresh fails startup: close the container so it can be retried.
+            if let Err(close_error) = self.close() {
+                log::error!("Failed to close the container: {}", close_error);
+            }
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Async counterpart of [`IocContainer::initialize`]: eager singletons with async
+    /// constructors (`async fn new`) are built by awaiting them rather than blocking.
+    ///
+    /// With a startup parallelism above 1 (see [`IocContainer::set_startup_parallelism`]),
+    /// independent singletons are created concurrently on the current tokio runtime.
+    pub async fn initialize_async(self: &Arc<Self>) -> Result<(), IocError> {
+        if !self.register_discovered_beans()? {
+            return Ok(());
+        }
+        if *self.eager_init.read() {
+            self.preinstantiate_singletons_async().await?;
+        }
+        log::info!("Summer IOC container initialized successfully.");
+        if let Err(e) = self.publish_event_async(ContextRefreshed).await {
+            if let Err(close_error) = self.close_async().await {
+                log::error!("Failed to close the container: {}", close_error);
+            }
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Registers the `#[component]` beans, marks the container initialized and rejects
+    /// dependency cycles. Returns `false` if the container was already initialized.
+    fn register_discovered_beans(&self) -> Result<bool, IocError> {
+        let mut initialized_guard = self.initialized.write();
+        if *initialized_guard {
+            return Ok(false);
+        }
 
         log::info!("Initializing Summer IOC container..."); // Added logging
 
-        for metadata in summer_core::inventory::iter::<BeanDefinitionMetadata>() {
+        if let Err(e) = self.register_components() {
+            self.remove_discovered_beans();
+            return Err(e);
+        }
+        self.evaluate_conditions();
+
+        *initialized_guard = true;
+        // Release the lock: eager instantiation goes through the regular lookup path.
+        drop(initialized_guard);
+
+        if let Some(cycle) = self.dependency_graph().find_cycle() {
+            let error = IocError::DependencyCycle(cycle[0].clone(), cycle);
+            log::error!("{}", error);
+            *self.initialized.write() = false;
+            self.remove_discovered_beans();
+            return Err(error);
+        }
+        Ok(true)
+    }
+
+    /// Registers a definition for every `#[component]` that has not been replaced.
+    fn register_components(&self) -> Result<(), IocError> {
+        // Discovered components belong to the root container only.
+        let discovered = match self.parent {
+            Some(_) => None,
+            None => Some(summer_core::inventory::iter::<BeanDefinitionMetadata>()),
+        };
+        for metadata in discovered.into_iter().flatten() {
             let bean_name = metadata.bean_name.to_string();
+            if self.replaced_beans.read().contains(&bean_name) {
+                log::info!(
+                    "Bean '{}' has been replaced, skipping its component.",
+                    bean_name
+                );
+                continue;
+            }
             let bean_type_id = (metadata.bean_type_id)();
             log::debug!(
                 "Registering bean definition: name='{}', type_id={:?}",
                 bean_name,
                 bean_type_id
             ); // Added logging
-               // metadata.constructor is already BeanConstructor type
-            let definition =
-                BeanDefinition::new(bean_name.clone(), bean_type_id, metadata.constructor);
+            let definition = match metadata.async_constructor {
+                Some(constructor) => {
+                    BeanDefinition::new_async(bean_name.clone(), bean_type_id, constructor)
+                }
+                // metadata.constructor is already BeanConstructor type
+                None => BeanDefinition::new(bean_name.clone(), bean_type_id, metadata.constructor),
+            };
+            let mut definition = definition
+                .with_type_name((metadata.bean_type_name)())
+                .with_primary(metadata.is_primary)
+                .with_lazy(metadata.lazy)
+                .with_scope(BeanScope::from_name(metadata.scope));
+            for provided in metadata.provides {
+                self.register_type_name((provided.type_id)(), (provided.type_name)());
+                definition = definition.with_provided_type((provided.type_id)(), provided.caster);
+            }
+            for qualifier in metadata.qualifiers {
+                definition = definition.with_qualifier(*qualifier);
+            }
+            if let Some(callback) = metadata.post_construct {
+                definition = definition.with_post_construct(callback);
+            }
+            if let Some(callback) = metadata.pre_destroy {
+                definition = definition.with_pre_destroy(callback);
+            }
+            for dependency in metadata.dependencies {
+                self.register_type_name((dependency.type_id)(), (dependency.type_name)());
+                definition = definition.with_dependency(BeanDependency {
+                    type_id: (dependency.type_id)(),
+                    type_name: (dependency.type_name)().to_string(),
+                    qualifier: dependency.qualifier.map(str::to_string),
+                    optional: dependency.optional,
+                    collection: dependency.collection,
+                    lazy: dependency.lazy,
+                });
+            }
+            if let Some(order) = metadata.order {
+                definition = definition.with_order(order);
+            }
+            for condition in metadata.conditions {
+                definition = definition.with_condition(condition.into());
+            }
+            definition.bean_name_aware = metadata.bean_name_aware;
+            definition.container_aware = metadata.container_aware;
             // Use internal registration which handles locking
-            self.register_bean_definition_internal(definition)?;
+            self.register_bean_definition_internal(definition, *self.override_policy.read())?;
+            self.discovered_beans.lock().push(bean_name);
         }
-
-        *initialized_guard = true;
-        log::info!("Summer IOC container initialized successfully."); // Added logging
         Ok(())
     }
 
+    /// Removes the definitions registered from `#[component]`s, keeping those registered
+    /// programmatically, and restores the programmatic definitions skipped by their conditions.
+    fn remove_discovered_beans(&self) {
+        for bean_name in std::mem::take(&mut *self.discovered_beans.lock()) {
+            self.remove_definition(&bean_name);
+        }
+        for definition in std::mem::take(&mut *self.skipped_definitions.lock()) {
+            let bean_name = definition.bean_name.clone();
+            if let Err(e) =
+                self.register_bean_definition_internal(definition, BeanOverridePolicy::Deny)
+            {
+                log::warn!("Dropping skipped bean definition '{}': {}", bean_name, e);
+            }
+        }
+    }
+
+    /// Builds the graph of declared dependencies between registered beans, without
+    /// instantiating anything. Each dependency is resolved the same way injection would
+    /// resolve it; dependencies that cannot be resolved are listed as unresolved.
+    pub fn dependency_graph(&self) -> DependencyGraph {
+        let definitions: Vec<(String, Vec<BeanDependency>)> = self
+            .definitions
+            .read()
+            .values()
+            .map(|def| (def.bean_name.clone(), def.dependencies.clone()))
+            .collect();
+
+        let mut graph = DependencyGraph::default();
+        for (bean_name, dependencies) in definitions {
+            for dependency in dependencies {
+                if dependency.collection {
+                    // Every bean of the type is injected; having none is not an error.
+                    for to in self.bean_names_for_type(dependency.type_id) {
+                        graph.edges.push(DependencyEdge {
+                            from: bean_name.clone(),
+                            to,
+                            optional: true,
+                            lazy: dependency.lazy,
+                        });
+                    }
+                    continue;
+                }
+                let dependency_type = self.type_info(dependency.type_id);
+                match self.resolve_bean_name(dependency_type, dependency.qualifier.as_deref()) {
+                    Ok(to) => graph.edges.push(DependencyEdge {
+                        from: bean_name.clone(),
+                        to,
+                        optional: dependency.optional,
+                        lazy: dependency.lazy,
+                    }),
+                    Err(e) => graph.unresolved.push(UnresolvedDependency {
+                        bean_name: bean_name.clone(),
+                        type_name: dependency.type_name,
+                        qualifier: dependency.qualifier,
+                        optional: dependency.optional,
+                        reason: e.to_string(),
+                    }),
+                }
+            }
+            graph.nodes.push(bean_name);
+        }
+        graph.nodes.sort();
+        graph
+            .edges
+            .sort_by(|a, b| (&a.from, &a.to).cmp(&(&b.from, &b.to)));
+        graph
+            .unresolved
+            .sort_by(|a, b| (&a.bean_name, &a.type_name).cmp(&(&b.bean_name, &b.type_name)));
+        graph
+    }
+
+    /// Describes every registered bean, sorted by name. Nothing is instantiated; the
+    /// descriptors reflect the container at the time of the call.
+    pub fn bean_descriptors(&self) -> impl Iterator<Item = BeanDescriptor> {
+        let graph = self.dependency_graph();
+        let definitions: Vec<(String, &'static str, BeanScope)> = self
+            .definitions
+            .read()
+            .values()
+            .map(|def| (def.bean_name.clone(), def.bean_type_name, def.scope.clone()))
+            .collect();
+        let instances = self.singleton_instances.read();
+        let created_at = self.singleton_created_at.read();
+        let mut descriptors: Vec<BeanDescriptor> = definitions
+            .into_iter()
+            .map(|(name, type_name, scope)| BeanDescriptor {
+                instantiated: scope == BeanScope::Singleton && instances.contains_key(&name),
+                created_at: created_at.get(&name).copied(),
+                dependencies: graph.dependencies_of(&name).map(str::to_string).collect(),
+                type_name,
+                scope,
+                name,
+            })
+            .collect();
+        descriptors.sort_by(|a, b| a.name.cmp(&b.name));
+        descriptors.into_iter()
+    }
+
+    /// Report of every bean and every skipped bean, e.g. for an admin endpoint; see
+    /// [`ContainerReport::to_json`].
+    pub fn report(&self) -> ContainerReport {
+        ContainerReport {
+            initialized: *self.initialized.read(),
+            beans: self.bean_descriptors().collect(),
+            skipped_beans: self.skipped_beans(),
+        }
+    }
+
+    /// Sets a property for `#[conditional_on_property]` conditions. Conditions are evaluated
+    /// by `initialize`, so properties must be set before it.
+    pub fn set_property(&self, name: impl Into<String>, value: impl Into<String>) {
+        self.properties.write().insert(name.into(), value.into());
+    }
+
+    /// Replaces the active profiles consulted by `#[profile(..)]` conditions. Must be called
+    /// before `initialize`.
+    pub fn set_active_profiles<I, S>(&self, profiles: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        *self.active_profiles.write() = profiles.into_iter().map(Into::into).collect();
+    }
+
+    /// The beans `initialize` did not register because one of their conditions failed,
+    /// with the reason, sorted by bean name.
+    pub fn skipped_beans(&self) -> Vec<SkippedBean> {
+        self.skipped_beans.read().clone()
+    }
+
+    /// Drops the beans whose conditions do not hold and records why.
+    ///
+    /// Property, profile and feature conditions are checked first, for every bean. Then
+    /// `OnMissingBean` conditions are checked in bean name order, each against the beans still
+    /// registered except those whose own `OnMissingBean` conditions are yet to be checked.
+    /// So among several fallbacks for one type, the first by name is kept.
+    fn evaluate_conditions(&self) {
+        let properties = self.properties.read().clone();
+        let active_profiles = self.active_profiles.read().clone();
+        let mut conditional: Vec<(String, Vec<BeanCondition>)> = self
+            .definitions
+            .read()
+            .values()
+            .filter(|def| !def.conditions.is_empty())
+            .map(|def| (def.bean_name.clone(), def.conditions.clone()))
+            .collect();
+        conditional.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut skipped = Vec::new();
+        let mut awaiting_missing_bean = Vec::new();
+        for (bean_name, conditions) in conditional {
+            let failed = conditions
+                .iter()
+                .find_map(|condition| condition.check_environment(&properties, &active_profiles));
+            if let Some(reason) = failed {
+                skipped.push(SkippedBean { bean_name, reason });
+                continue;
+            }
+            let missing: Vec<(TypeId, String)> = conditions
+                .into_iter()
+                .filter_map(|condition| match condition {
+                    BeanCondition::OnMissingBean { type_id, type_name } => {
+                        Some((type_id, type_name))
+                    }
+                    _ => None,
+                })
+                .collect();
+            if !missing.is_empty() {
+                awaiting_missing_bean.push((bean_name, missing));
+            }
+        }
+        for skipped_bean in &skipped {
+            self.remove_skipped_definition(&skipped_bean.bean_name);
+        }
+
+        let mut undecided: HashSet<String> = awaiting_missing_bean
+            .iter()
+            .map(|(bean_name, _)| bean_name.clone())
+            .collect();
+        for (bean_name, missing) in awaiting_missing_bean {
+            undecided.remove(&bean_name);
+            let existing = missing.iter().find_map(|(type_id, type_name)| {
+                self.bean_names_for_type(*type_id)
+                    .into_iter()
+                    .find(|other| *other != bean_name && !undecided.contains(other))
+                    .map(|other| format!("bean '{}' of type {} already exists", other, type_name))
+            });
+            if let Some(reason) = existing {
+                self.remove_skipped_definition(&bean_name);
+                skipped.push(SkippedBean { bean_name, reason });
+            }
+        }
+
+        skipped.sort_by(|a, b| a.bean_name.cmp(&b.bean_name));
+        for skipped_bean in &skipped {
+            log::info!(
+                "Skipping bean '{}': {}",
+                skipped_bean.bean_name,
+                skipped_bean.reason
+            );
+        }
+        *self.skipped_beans.write() = skipped;
+    }
+
+    /// Removes the definition `name` and its entries in the type index.
+    fn remove_definition(&self, name: &str) -> Option<BeanDefinition> {
+        let mut definitions_guard = self.definitions.write();
+        let mut beans_by_type_guard = self.beans_by_type.write();
+        for names in beans_by_type_guard.values_mut() {
+            names.retain(|registered| registered != name);
+        }
+        definitions_guard.remove(name)
+    }
+
+    /// Removes the definition of a bean whose conditions failed, keeping it aside if it was
+    /// registered programmatically; components are registered again by every `initialize`.
+    fn remove_skipped_definition(&self, name: &str) {
+        let definition = self.remove_definition(name);
+        let discovered = self.discovered_beans.lock().iter().any(|bean| bean == name);
+        if let Some(definition) = definition.filter(|_| !discovered) {
+            self.skipped_definitions.lock().push(definition);
+        }
+    }
+
+    /// Enables or disables eager instantiation of non-lazy singletons in `initialize`.
+    /// Eager mode is the default, so configuration errors surface at startup.
+    pub fn set_eager_init(&self, eager: bool) {
+        *self.eager_init.write() = eager;
+    }
+
+    /// Creates every non-lazy singleton, collecting all failures instead of stopping at the
+    /// first. On failure the singletons created so far are destroyed and the container is
+    /// left closed.
+    fn preinstantiate_singletons(&self) -> Result<(), IocError> {
+        let failures: Vec<(String, IocError)> = self
+            .eager_singleton_names()
+            .into_iter()
+            .filter_map(|name| match self.get_bean_by_name_any(&name) {
+                Ok(_) => None,
+                Err(e) => Some((name, e)),
+            })
+            .collect();
+        if failures.is_empty() {
+            return Ok(());
+        }
+
+        let error = IocError::StartupFailed(failures);
+        log::error!("{}", error);
+        if let Err(e) = self.close() {
+            log::error!("Failed to clean up after startup failure: {}", e);
+        }
+        Err(error)
+    }
+
+    /// Async counterpart of `preinstantiate_singletons`.
+    async fn preinstantiate_singletons_async(self: &Arc<Self>) -> Result<(), IocError> {
+        let parallelism = *self.startup_parallelism.read();
+        let mut failures = Vec::new();
+        if parallelism > 1 {
+            failures = self.create_singletons_concurrently(parallelism).await;
+        } else {
+            for name in self.eager_singleton_names() {
+                if let Err(e) = self.prepare_bean_async(&name).await {
+                    failures.push((name, e));
+                }
+            }
+        }
+        if failures.is_empty() {
+            return Ok(());
+        }
+
+        let error = IocError::StartupFailed(failures);
+        log::error!("{}", error);
+        if let Err(e) = self.close_async().await {
+            log::error!("Failed to clean up after startup failure: {}", e);
+        }
+        Err(error)
+    }
+
+    /// Creates the eager singletons, and the singletons they depend on, at most `limit` at a
+    /// time. A bean is started once every singleton it depends on (directly or through beans
+    /// of other scopes) has been created. Async constructors run as tasks, synchronous ones on
+    /// the blocking thread pool.
+    ///
+    /// Returns the failures of eager singletons sorted by bean name, as the sequential startup
+    /// would report them. A failed dependency does not stop its dependents from being
+    /// attempted; their errors carry the resolution path down to it. Beans looking up
+    /// dependencies they did not declare can still wait on each other's creation locks;
+    /// `create_singleton` resolves such waits the way sequential creation would.
+    async fn create_singletons_concurrently(
+        self: &Arc<Self>,
+        limit: usize,
+    ) -> Vec<(String, IocError)> {
+        let eager = self.eager_singleton_names();
+        let graph = self.dependency_graph();
+        let (singletons, async_beans) = {
+            let definitions_guard = self.definitions.read();
+            let singletons: HashSet<String> = definitions_guard
+                .values()
+                .filter(|def| def.scope == BeanScope::Singleton)
+                .map(|def| def.bean_name.clone())
+                .collect();
+            let async_beans: HashSet<String> = definitions_guard
+                .values()
+                .filter(|def| def.requires_async_creation())
+                .map(|def| def.bean_name.clone())
+                .collect();
+            (singletons, async_beans)
+        };
+
+        // Unfinished singleton dependencies of every bean to create, and the reverse edges.
+        let mut waiting_for: HashMap<String, BTreeSet<String>> = HashMap::new();
+        let mut to_visit = eager.clone();
+        while let Some(name) = to_visit.pop() {
+            if waiting_for.contains_key(&name) {
+                continue;
+            }
+            let mut dependencies = BTreeSet::new();
+            collect_startup_dependencies(&graph, &singletons, &name, &mut dependencies);
+            to_visit.extend(dependencies.iter().cloned());
+            waiting_for.insert(name, dependencies);
+        }
+        let mut dependents: HashMap<String, Vec<String>> = HashMap::new();
+        for (name, dependencies) in &waiting_for {
+            for dependency in dependencies {
+                dependents
+                    .entry(dependency.clone())
+                    .or_default()
+                    .push(name.clone());
+            }
+        }
+        // Ordered, so beans are started in the same order on every run.
+        let mut ready: BTreeSet<String> = waiting_for
+            .iter()
+            .filter(|(_, dependencies)| dependencies.is_empty())
+            .map(|(name, _)| name.clone())
+            .collect();
+        log::debug!(
+            "Creating {} singleton(s) with up to {} at a time",
+            waiting_for.len(),
+            limit
+        );
+
+        let eager: HashSet<String> = eager.into_iter().collect();
+        let mut failures = Vec::new();
+        let mut running = tokio::task::JoinSet::new();
+        let mut running_names = HashMap::new();
+        loop {
+            while running.len() < limit {
+                let Some(name) = ready.pop_first() else {
+                    break;
+                };
+                let container = self.clone();
+                let bean_name = name.clone();
+                let task = if async_beans.contains(&name) {
+                    running.spawn(async move {
+                        container.prepare_bean_async(&bean_name).await.map(drop)
+                    })
+                } else {
+                    running.spawn_blocking(move || {
+                        container.get_bean_by_name_any(&bean_name).map(drop)
+                    })
+                };
+                running_names.insert(task.id(), name);
+            }
+
+            let (name, result) = match running.join_next_with_id().await {
+                None => break,
+                Some(Ok((id, result))) => (running_names.remove(&id), result),
+                Some(Err(e)) => (
+                    running_names.remove(&e.id()),
+                    Err(IocError::InternalError(format!(
+                        "bean creation task failed: {}",
+                        e
+                    ))),
+                ),
+            };
+            let Some(name) = name else {
+                continue;
+            };
+            if let Err(e) = result {
+                if eager.contains(&name) {
+                    failures.push((name.clone(), e));
+                }
+            }
+            for dependent in dependents.remove(&name).unwrap_or_default() {
+                if let Some(dependencies) = waiting_for.get_mut(&dependent) {
+                    dependencies.remove(&name);
+                    if dependencies.is_empty() {
+                        ready.insert(dependent);
+                    }
+                }
+            }
+        }
+        failures.sort_by(|a, b| a.0.cmp(&b.0));
+        failures
+    }
+
+    /// Maximum number of singletons `initialize_async` creates at the same time. The default
+    /// of 1 creates them one by one; 0 is treated as 1. `initialize` is always sequential.
+    pub fn set_startup_parallelism(&self, limit: usize) {
+        *self.startup_parallelism.write() = limit.max(1);
+    }
+
+    /// Names of the singletons created at startup, sorted for a deterministic creation order
+    /// and failure report.
+    fn eager_singleton_names(&self) -> Vec<String> {
+        let mut bean_names: Vec<String> = self
+            .definitions
+            .read()
+            .values()
+            .filter(|def| def.is_eager_singleton())
+            .map(|def| def.bean_name.clone())
+            .collect();
+        bean_names.sort();
+        log::debug!("Pre-instantiating {} singleton(s)", bean_names.len());
+        bean_names
+    }
+
     /// Internal registration logic, now takes &self
     fn register_bean_definition_internal(
         &self,
         definition: BeanDefinition,
+        policy: BeanOverridePolicy,
     ) -> Result<(), IocError> {
+        if self.is_frozen() {
+            log::error!(
+                "Cannot register bean '{}': the container is frozen.",
+                definition.bean_name
+            );
+            return Err(IocError::ContainerFrozen);
+        }
         let bean_name = definition.bean_name.clone();
         let bean_type_id = definition.bean_type_id;
+        if definition.bean_type_name != UNKNOWN_TYPE_NAME {
+            self.register_type_name(bean_type_id, definition.bean_type_name);
+        }
+        let provided_type_ids: Vec<TypeId> = definition
+            .provides
+            .iter()
+            .map(|provided| provided.type_id)
+            .collect();
 
         // Acquire write locks
         let mut definitions_guard = self.definitions.write();
         let mut beans_by_type_guard = self.beans_by_type.write();
 
         // Check for duplicate bean names
-        if definitions_guard.contains_key(&bean_name) {
-            log::error!(
-                "Bean registration failed: Bean with name '{}' already exists.",
-                bean_name
-            ); // Added logging
-            return Err(IocError::BeanAlreadyExists(bean_name));
+        let replaced = definitions_guard.get(&bean_name).map(|previous| {
+            std::iter::once(previous.bean_type_id)
+                .chain(previous.provides.iter().map(|provided| provided.type_id))
+                .collect::<Vec<TypeId>>()
+        });
+        if let Some(previous_type_ids) = replaced {
+            match policy {
+                BeanOverridePolicy::Deny => {
+                    log::error!(
+                        "Bean registration failed: Bean with name '{}' already exists.",
+                        bean_name
+                    ); // Added logging
+                    return Err(IocError::BeanAlreadyExists(bean_name));
+                }
+                BeanOverridePolicy::AllowWithWarning => {
+                    log::warn!("Overriding the definition of bean '{}'.", bean_name)
+                }
+                BeanOverridePolicy::Allow => {
+                    log::debug!("Overriding the definition of bean '{}'.", bean_name)
+                }
+            }
+            // Drop the old definition's type entries, which may differ from the new ones.
+            for type_id in previous_type_ids {
+                if let Some(names) = beans_by_type_guard.get_mut(&type_id) {
+                    names.retain(|registered| *registered != bean_name);
+                }
+            }
+            // An instance built from the old definition must not be handed out any more.
+            self.singleton_instances.write().remove(&bean_name);
+            self.singleton_created_at.write().remove(&bean_name);
         }
 
         // Insert definition
         definitions_guard.insert(bean_name.clone(), definition);
 
-        // Update type mapping
-        beans_by_type_guard
-            .entry(bean_type_id)
-            .or_default()
-            .push(bean_name.clone());
+        // Update type mapping, for the concrete type and every provided (trait object) type
+        for type_id in std::iter::once(bean_type_id).chain(provided_type_ids) {
+            beans_by_type_guard
+                .entry(type_id)
+                .or_default()
+                .push(bean_name.clone());
+        }
 
         log::trace!("Successfully registered bean definition: '{}'", bean_name); // Added logging
         Ok(())
@@ -164,11 +879,201 @@ impl IocContainer {
             "Dynamically registering bean definition: '{}'",
             definition.bean_name
         ); // Added logging
-        self.register_bean_definition_internal(definition)
+        self.register_bean_definition_internal(definition, *self.override_policy.read())
+    }
+
+    /// Sets what happens when a definition is registered under a name that is already taken,
+    /// including by the `#[component]`s registered in `initialize`.
+    pub fn set_override_policy(&self, policy: BeanOverridePolicy) {
+        *self.override_policy.write() = policy;
+    }
+
+    /// Replaces the bean `definition.bean_name` with `definition`, whatever the overriding
+    /// policy, e.g. to swap a real client for a mock in a test. When called before `initialize`
+    /// the replacement also wins over the `#[component]` of that name.
+    ///
+    /// Fails with `IocError::ContainerFrozen` once the container is frozen.
+    pub fn replace_bean_definition(&self, definition: BeanDefinition) -> Result<(), IocError> {
+        log::info!("Replacing bean definition '{}'", definition.bean_name);
+        let bean_name = definition.bean_name.clone();
+        self.register_bean_definition_internal(definition, BeanOverridePolicy::Allow)?;
+        // The replacement outlives a reset of the container, unlike the component it replaces.
+        self.discovered_beans
+            .lock()
+            .retain(|discovered| *discovered != bean_name);
+        self.replaced_beans.write().insert(bean_name);
+        Ok(())
+    }
+
+    /// Replaces the bean `name` with the singleton `instance`, found by type `T`. Use
+    /// [`IocContainer::replace_bean_definition`] when the replacement must also be registered
+    /// under a trait object type.
+    pub fn replace_bean_with_instance<T: Any + Send + Sync>(
+        &self,
+        name: impl Into<String>,
+        instance: Arc<T>,
+    ) -> Result<(), IocError> {
+        self.replace_bean_definition(BeanDefinition::for_instance(name, instance))
+    }
+
+    /// Registers an already built instance as a singleton bean named `name`.
+    ///
+    /// The bean is found by type and injected like a `#[component]`; use
+    /// [`BeanDefinition::for_instance`] with `register_bean_definition` to add qualifiers or
+    /// lifecycle callbacks.
+    pub fn register_singleton<T: Any + Send + Sync>(
+        &self,
+        name: impl Into<String>,
+        instance: Arc<T>,
+    ) -> Result<(), IocError> {
+        self.register_bean_definition(BeanDefinition::for_instance(name, instance))
+    }
+
+    /// Registers a singleton bean named `name` built by `factory` on first use (or at startup
+    /// in eager mode). The factory may capture state and resolve dependencies from the provider.
+    pub fn register_factory<T, F>(
+        &self,
+        name: impl Into<String>,
+        factory: F,
+    ) -> Result<(), IocError>
+    where
+        T: Any + Send + Sync,
+        F: Fn(&BeanProviderRef) -> Result<T, ConstructorError> + Send + Sync + 'static,
+    {
+        self.register_bean_definition(BeanDefinition::for_factory(name, factory))
+    }
+
+    /// Registers a custom scope under `name`, for beans declared with `scope = "<name>"`.
+    /// Replaces any scope previously registered under the same name.
+    pub fn register_scope(&self, name: impl Into<String>, scope: Arc<dyn Scope>) {
+        let name = name.into();
+        log::info!("Registering custom bean scope '{}'", name);
+        self.scopes.write().insert(name, scope);
+    }
+
+    /// Registers a processor that sees every bean created from now on. Processors run in
+    /// registration order; register them before `initialize` so eager singletons pass through
+    /// them too.
+    pub fn register_bean_post_processor(&self, processor: Arc<dyn BeanPostProcessor>) {
+        log::info!("Registering bean post-processor");
+        self.post_processors.write().push(processor);
+    }
+
+    /// Closes the container: runs the `#[pre_destroy]` callbacks of every created singleton in
+    /// reverse creation order (dependents before their dependencies) and clears the singleton
+    /// cache. Async callbacks are not run but reported as failures; close containers with async
+    /// callbacks with [`IocContainer::close_async`].
+    ///
+    /// Every callback runs even if an earlier one fails; the first failure is returned. A closed
+    /// container can be initialized again, creating new singletons.
+    pub fn close(&self) -> Result<(), IocError> {
+        if *self.initialized.read() {
+            if let Err(e) = self.publish_event(ContextClosing) {
+                log::error!("Failed to publish the context closing event: {}", e);
+            }
+        }
+        let mut first_error = None;
+        for command in self.take_shutdown_commands().iter().rev() {
+            if let Err(e) = command.execute() {
+                log::error!("Failed to destroy bean '{}': {}", command.bean_name(), e);
+                first_error.get_or_insert(e);
+            }
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Async version of [`IocContainer::close`] that awaits async `#[pre_destroy]` callbacks.
+    pub async fn close_async(&self) -> Result<(), IocError> {
+        if *self.initialized.read() {
+            if let Err(e) = self.publish_event_async(ContextClosing).await {
+                log::error!("Failed to publish the context closing event: {}", e);
+            }
+        }
+        let mut first_error = None;
+        for command in self.take_shutdown_commands().iter().rev() {
+            if let Err(e) = command.execute_async().await {
+                log::error!("Failed to destroy bean '{}': {}", command.bean_name(), e);
+                first_error.get_or_insert(e);
+            }
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Marks the container as closed, detaches its singletons and drops the discovered
+    /// `#[component]` definitions, returning the pending shutdown commands in creation order.
+    fn take_shutdown_commands(&self) -> Vec<PreDestroyCommand> {
+        log::info!("Closing Summer IOC container...");
+        *self.initialized.write() = false;
+        self.frozen.store(None);
+        self.singleton_instances.write().clear();
+        self.singleton_created_at.write().clear();
+        self.event_multicaster.remove_bean_listeners();
+        self.remove_discovered_beans();
+        std::mem::take(&mut *self.shutdown_commands.lock())
+    }
+
+    /// The multicaster delivering this container's events.
+    pub fn event_multicaster(&self) -> &ApplicationEventMulticaster {
+        &self.event_multicaster
+    }
+
+    /// Registers a listener for every published event of type `E`.
+    pub fn add_application_listener<E: ApplicationEvent>(
+        &self,
+        listener: Arc<dyn ApplicationListener<E>>,
+    ) {
+        self.event_multicaster.add_listener(listener);
+    }
+
+    /// Delivers `event` to its synchronous listeners on the calling thread; async listeners are
+    /// skipped, use [`IocContainer::publish_event_async`] to reach them. Returns the error of
+    /// the first listener that fails; later listeners are skipped.
+    pub fn publish_event<E: ApplicationEvent>(&self, event: E) -> Result<(), IocError> {
+        self.event_multicaster.multicast_event(Arc::new(event))
+    }
+
+    /// Async counterpart of [`IocContainer::publish_event`], awaiting async listeners.
+    pub async fn publish_event_async<E: ApplicationEvent>(&self, event: E) -> Result<(), IocError> {
+        self.event_multicaster
+            .multicast_event_async(Arc::new(event))
+            .await
+    }
+
+    /// Publishes `BeanCreated` for `bean_name`. Listener errors are logged: they must not fail
+    /// the creation of the bean.
+    fn publish_bean_created(&self, bean_name: &str) {
+        let event = BeanCreated {
+            bean_name: bean_name.to_string(),
+        };
+        if let Err(e) = self.publish_event(event) {
+            log::error!(
+                "Failed to publish the creation of bean '{}': {}",
+                bean_name,
+                e
+            );
+        }
+    }
+
+    /// Async counterpart of `publish_bean_created`, awaiting async listeners.
+    async fn publish_bean_created_async(&self, bean_name: &str) {
+        let event = BeanCreated {
+            bean_name: bean_name.to_string(),
+        };
+        if let Err(e) = self.publish_event_async(event).await {
+            log::error!(
+                "Failed to publish the creation of bean '{}': {}",
+                bean_name,
+                e
+            );
+        }
     }
 
     /// Retrieves a bean instance by name, returning BeanInstance.
     fn get_bean_by_name_any(&self, name: &str) -> Result<BeanInstance, IocError> {
+        if let Some(instance) = self.frozen_bean(|frozen| frozen.by_name.get(name)) {
+            return Ok(instance);
+        }
+
         // Check initialization status first.
         if !*self.initialized.read() {
             log::warn!(
@@ -190,21 +1095,31 @@ impl IocContainer {
 
         log::trace!("Cache miss for bean '{}', attempting instantiation.", name); // Added logging
 
+        if let Some(parent) = &self.parent {
+            if !self.definitions.read().contains_key(name) {
+                log::trace!("Bean '{}' is not defined locally, asking the parent.", name);
+                return parent
+                    .get_bean_instance_by_name(name)
+                    .map_err(from_parent_error);
+            }
+        }
+
         // 2. Instantiate (needs BeanProviderRef)
-        // Clone the BeanProviderRef stored internally.
-        let self_provider_ref = self.self_arc.read().clone().ok_or_else(|| {
-            // This indicates a critical internal error if None.
-            log::error!(
-                "Internal container error: self_arc is None during bean instantiation for '{}'.",
-                name
-            );
-            IocError::InternalError("Container self_arc not initialized".to_string())
-        })?;
+        let self_provider_ref = self.provider_ref()?;
 
         // Call the main instantiation logic, passing the BeanProviderRef
         self.instantiate_bean(self_provider_ref, name)
     }
 
+    /// Clones the BeanProviderRef stored internally, which is handed to constructors.
+    fn provider_ref(&self) -> Result<BeanProviderRef, IocError> {
+        self.self_arc.read().clone().ok_or_else(|| {
+            // This indicates a critical internal error if None.
+            log::error!("Internal container error: self_arc is None during bean instantiation.");
+            IocError::InternalError("Container self_arc not initialized".to_string())
+        })
+    }
+
     /// Retrieves a bean instance by its name, downcasting to the requested type T.
     pub fn get_bean_by_name<T: Any + Send + Sync>(&self, name: &str) -> Result<Arc<T>, IocError> {
         let requested_type_id = TypeId::of::<T>();
@@ -220,7 +1135,7 @@ impl IocContainer {
         // Attempt to downcast to the requested concrete type T.
         instance_any.downcast::<T>().map_err(|arc| {
             // Downcast failed, means the stored type doesn't match T.
-            let stored_type_id = arc.type_id();
+            let stored_type_id = (*arc).type_id();
             log::error!(
                 "Type mismatch for bean '{}': Requested type {:?}, but stored type is {:?}.",
                 name,
@@ -229,39 +1144,46 @@ impl IocContainer {
             ); // Added logging
             IocError::TypeMismatchError {
                 bean_name: name.to_string(),
-                requested: requested_type_id,
-                stored: stored_type_id,
+                requested: TypeInfo::of::<T>(),
+                stored: self.type_info(stored_type_id),
             }
         })
     }
 
     /// Internal helper to handle instantiation, locking, and caching. Takes BeanProviderRef.
-    // Returns Result<BeanInstance, IocError>
+    ///
+    /// If the thread backed off from a creation lock to avoid a deadlock, the outermost
+    /// creation on the thread waits for that lock and starts over, so the outcome is the one a
+    /// single thread creating the beans one after the other would see.
     fn instantiate_bean(
-        &self,                         // Keep &self for accessing container fields
-        provider_ref: BeanProviderRef, // Pass BeanProviderRef for the constructor call
+        &self,
+        provider_ref: BeanProviderRef,
         name: &str,
     ) -> Result<BeanInstance, IocError> {
-        // --- Cycle Detection Start (Write Lock on currently_in_creation) ---
-        {
-            let mut creating_guard = self.currently_in_creation.write();
-            if !creating_guard.insert(name.to_string()) {
-                // If insert returns false, the name was already present. Cycle detected.
-                let cycle_path = creating_guard.iter().cloned().collect();
-                log::error!(
-                    "Dependency cycle detected while creating bean '{}'. Path: {:?}",
-                    name,
-                    cycle_path
-                ); // Added logging
-                return Err(IocError::DependencyCycle(name.to_string(), cycle_path));
+        loop {
+            let result = self.try_instantiate_bean(provider_ref.clone(), name);
+            if self.is_creating_beans() {
+                return result;
+            }
+            match (self.take_creation_retry(), &result) {
+                (Some(contended), Err(e)) => {
+                    log::debug!("Retrying creation of bean '{}' after: {}", name, e);
+                    drop(contended.lock());
+                }
+                _ => return result,
             }
-            log::trace!(
-                "Starting creation of bean '{}'. Current creation path: {:?}",
-                name,
-                creating_guard.iter()
-            ); // Added logging
-               // Write lock is released here
         }
+    }
+
+    /// A single attempt of `instantiate_bean`.
+    // Returns Result<BeanInstance, IocError>
+    fn try_instantiate_bean(
+        &self,                         // Keep &self for accessing container fields
+        provider_ref: BeanProviderRef, // Pass BeanProviderRef for the constructor call
+        name: &str,
+    ) -> Result<BeanInstance, IocError> {
+        // --- Cycle Detection Start (this thread's resolution chain) ---
+        self.enter_creation(name)?;
 
         // --- Get Definition (Read Lock on definitions) ---
         // Clone the definition to avoid holding the read lock during potentially long constructor call.
@@ -274,40 +1196,31 @@ impl IocContainer {
         // --- Instantiate Bean (using the cloned definition) ---
         let bean_instance_result: Result<BeanInstance, IocError> = match definition {
             Some(def) => {
-                log::debug!("Found definition for bean '{}'. Calling constructor.", name); // Added logging
-                let constructor = def.constructor; // constructor is BeanConstructor type
-                                                   // Pass the BeanProviderRef to the constructor.
-                                                   // The constructor returns BeanConstructorResult (Result<BeanInstance, ConstructorError>)
-                let instance_arc_any: BeanInstance = constructor(provider_ref) // Pass the BeanProviderRef
-                    .map_err(|e| {
-                        // Constructor returned an error (Box<dyn Error>)
-                        log::error!("Failed to instantiate bean '{}': {}", name, e); // Added logging
-                        IocError::InstantiationError {
-                            bean_name: name.to_string(),
-                            reason: e.to_string(),
+                // Errors must not short-circuit here, otherwise the bean would never leave
+                // the creation chain.
+                match &def.scope {
+                    BeanScope::Singleton => self.create_singleton(&provider_ref, &def),
+                    // Prototype beans are never cached: every lookup builds a new instance.
+                    BeanScope::Prototype => self.construct_bean(&provider_ref, &def),
+                    BeanScope::Custom(scope_name) => {
+                        let scope = self.scopes.read().get(scope_name).cloned();
+                        match scope {
+                            Some(scope) => {
+                                scope.get(name, &|| self.construct_bean(&provider_ref, &def))
+                            }
+                            None => {
+                                log::error!(
+                                    "Bean '{}' uses unregistered scope '{}'.",
+                                    name,
+                                    scope_name
+                                );
+                                Err(IocError::ScopeNotRegistered {
+                                    bean_name: name.to_string(),
+                                    scope: scope_name.clone(),
+                                })
+                            }
                         }
-                    })?; // Propagate error if constructor fails
-
-                log::debug!(
-                    "Successfully constructed bean instance for '{}'. Caching...",
-                    name
-                ); // Added logging
-
-                // --- Store in Singleton Cache (Write Lock on singleton_instances) ---
-                // Use Double-Checked Locking pattern: Check cache again after acquiring write lock.
-                {
-                    let mut instances_guard = self.singleton_instances.write();
-                    if let Some(existing_instance) = instances_guard.get(name) {
-                        // Another thread might have created and cached the instance while we were waiting for the lock.
-                        log::trace!("Bean '{}' was already cached by another thread. Using cached instance.", name); // Added logging
-                        Ok(existing_instance.clone()) // Use the existing instance
-                    } else {
-                        // Cache is still empty for this name, insert the newly created instance.
-                        instances_guard.insert(name.to_string(), instance_arc_any.clone());
-                        log::trace!("Bean '{}' successfully cached.", name); // Added logging
-                        Ok(instance_arc_any) // Return the newly created instance
                     }
-                    // Write lock is released here
                 }
             }
             None => {
@@ -315,52 +1228,1062 @@ impl IocContainer {
                     "Bean definition not found for name '{}' during instantiation attempt.",
                     name
                 ); // Added logging
-                Err(IocError::BeanNotFoundByName(name.to_string()))
+                Err(self.bean_not_found_by_name(name))
             }
         };
 
-        // --- Cycle Detection End (Write Lock on currently_in_creation) ---
-        // Remove the bean name from the set regardless of success or failure.
-        {
-            let mut creating_guard = self.currently_in_creation.write();
-            creating_guard.remove(name);
-            log::trace!(
-                "Finished creation attempt for bean '{}'. Remaining in creation: {:?}",
+        // --- Cycle Detection End ---
+        // Remove the bean from the chain regardless of success or failure.
+        self.exit_creation(name);
+
+        bean_instance_result // Return the result (Ok(BeanInstance) or Err)
+    }
+
+    /// Calls the bean's constructor, passing the BeanProviderRef so it can resolve its dependencies.
+    fn construct_bean(
+        &self,
+        provider_ref: &BeanProviderRef,
+        def: &BeanDefinition,
+    ) -> Result<BeanInstance, IocError> {
+        let name = &def.bean_name;
+        if def.async_constructor.is_some() {
+            log::error!(
+                "Bean '{}' has an async constructor and cannot be created synchronously.",
+                name
+            );
+            return Err(IocError::AsyncConstructorRequired(name.clone()));
+        }
+        log::debug!("Found definition for bean '{}'. Calling constructor.", name); // Added logging
+        let instance = (def.constructor)(provider_ref.clone()).map_err(|e| {
+            log::error!("Failed to instantiate bean '{}': {}", name, e); // Added logging
+            instantiation_error(name, e)
+        })?;
+        log::debug!("Successfully constructed bean instance for '{}'.", name); // Added logging
+
+        let instance = self.prepare_initialization(provider_ref, def, instance)?;
+        if let Some(callback) = def.post_construct {
+            log::debug!("Running post-construct callback for bean '{}'.", name);
+            invoke_lifecycle_callback(callback, &instance)
+                .map_err(|e| post_construct_error(name, e))?;
+        }
+        let instance =
+            self.apply_post_processors(def, instance, "after_init", |processor, name, bean| {
+                processor.after_init(name, bean)
+            })?;
+        self.publish_bean_created(name);
+        Ok(instance)
+    }
+
+    /// Async counterpart of `construct_bean` for beans that require async creation: an async
+    /// constructor and an async `#[post_construct]` callback are awaited.
+    async fn construct_bean_async(
+        &self,
+        provider_ref: &BeanProviderRef,
+        def: &BeanDefinition,
+    ) -> Result<BeanInstance, IocError> {
+        let name = &def.bean_name;
+        let instance = match &def.async_constructor {
+            Some(constructor) => {
+                log::debug!("Awaiting async constructor of bean '{}'.", name);
+                constructor(provider_ref.clone()).await
+            }
+            None => (def.constructor)(provider_ref.clone()),
+        }
+        .map_err(|e| {
+            log::error!("Failed to instantiate bean '{}': {}", name, e);
+            instantiation_error(name, e)
+        })?;
+
+        let instance = self.prepare_initialization(provider_ref, def, instance)?;
+        if let Some(callback) = def.post_construct {
+            log::debug!("Running post-construct callback for bean '{}'.", name);
+            invoke_lifecycle_callback_async(callback, &instance)
+                .await
+                .map_err(|e| post_construct_error(name, e))?;
+        }
+        let instance =
+            self.apply_post_processors(def, instance, "after_init", |processor, name, bean| {
+                processor.after_init(name, bean)
+            })?;
+        self.publish_bean_created_async(name).await;
+        Ok(instance)
+    }
+
+    /// Everything between construction and `#[post_construct]`: the aware callbacks, then the
+    /// `before_init` post-processors.
+    fn prepare_initialization(
+        &self,
+        provider_ref: &BeanProviderRef,
+        def: &BeanDefinition,
+        instance: BeanInstance,
+    ) -> Result<BeanInstance, IocError> {
+        let name = &def.bean_name;
+        if let Some(notify) = def.bean_name_aware {
+            notify(&instance, name);
+        }
+        if let Some(notify) = def.container_aware {
+            // Weak, so beans holding the handle do not keep the container alive.
+            notify(&instance, &Arc::downgrade(provider_ref));
+        }
+        self.apply_post_processors(def, instance, "before_init", |processor, name, bean| {
+            processor.before_init(name, bean)
+        })
+    }
+
+    /// Passes `instance` through the `phase` hook of every registered post-processor, checking
+    /// that whatever they hand back is still of the bean's type.
+    fn apply_post_processors(
+        &self,
+        def: &BeanDefinition,
+        mut instance: BeanInstance,
+        phase: &'static str,
+        hook: impl Fn(&dyn BeanPostProcessor, &str, BeanInstance) -> BeanConstructorResult,
+    ) -> Result<BeanInstance, IocError> {
+        let name = &def.bean_name;
+        // Cloned so processors may look up other beans without holding the lock
+        let processors = self.post_processors.read().clone();
+        for processor in processors {
+            instance = hook(&*processor, name, instance).map_err(|e| {
+                log::error!("Post-processor {} failed for bean '{}': {}", phase, name, e);
+                IocError::PostProcessorError {
+                    bean_name: name.to_string(),
+                    phase,
+                    source: e,
+                }
+            })?;
+            let stored = (*instance).type_id();
+            if stored != def.bean_type_id {
+                log::error!(
+                    "Post-processor {} replaced bean '{}' with an instance of another type.",
+                    phase,
+                    name
+                );
+                return Err(IocError::TypeMismatchError {
+                    bean_name: name.to_string(),
+                    requested: self.type_info(def.bean_type_id),
+                    stored: self.type_info(stored),
+                });
+            }
+        }
+        Ok(instance)
+    }
+
+    /// Pushes `name` onto this thread's resolution chain, failing with the ordered cycle path
+    /// if it is already being created further up the chain.
+    fn enter_creation(&self, name: &str) -> Result<(), IocError> {
+        let container_id = self as *const Self as usize;
+        CREATION_CHAIN.with(|chain| {
+            let mut chain = chain.borrow_mut();
+            let in_this_container = || chain.iter().filter(|(id, _)| *id == container_id);
+            if let Some(start) = in_this_container().position(|(_, creating)| creating == name) {
+                let mut cycle_path: Vec<String> = in_this_container()
+                    .skip(start)
+                    .map(|(_, creating)| creating.clone())
+                    .collect();
+                cycle_path.push(name.to_string());
+                log::error!(
+                    "Dependency cycle detected while creating bean '{}'. Path: {}",
+                    name,
+                    cycle_path.join(" -> ")
+                ); // Added logging
+                return Err(IocError::DependencyCycle(name.to_string(), cycle_path));
+            }
+            chain.push((container_id, name.to_string()));
+            log::trace!("Starting creation of bean '{}'.", name); // Added logging
+            Ok(())
+        })
+    }
+
+    /// Whether this thread is creating a bean of this container further up its call stack.
+    fn is_creating_beans(&self) -> bool {
+        let container_id = self as *const Self as usize;
+        CREATION_CHAIN.with(|chain| chain.borrow().iter().any(|(id, _)| *id == container_id))
+    }
+
+    /// Takes the lock this thread backed off from, if it did so in this container.
+    fn take_creation_retry(&self) -> Option<Arc<Mutex<()>>> {
+        let container_id = self as *const Self as usize;
+        CREATION_RETRY.with(|retry| {
+            let mut retry = retry.borrow_mut();
+            match retry.take() {
+                Some((id, contended)) if id == container_id => Some(contended),
+                other => {
+                    *retry = other;
+                    None
+                }
+            }
+        })
+    }
+
+    /// Pops `name` off this thread's resolution chain.
+    fn exit_creation(&self, name: &str) {
+        let container_id = self as *const Self as usize;
+        CREATION_CHAIN.with(|chain| {
+            let mut chain = chain.borrow_mut();
+            if let Some(index) = chain
+                .iter()
+                .rposition(|(id, creating)| *id == container_id && creating == name)
+            {
+                chain.remove(index);
+            }
+        });
+        log::trace!("Finished creation attempt for bean '{}'.", name); // Added logging
+    }
+
+    /// Returns the cached singleton or constructs it while holding the bean's creation lock,
+    /// so concurrent first lookups build it exactly once.
+    ///
+    /// Constructors may look up beans they did not declare, so two threads can each hold a lock
+    /// the other needs. The thread that would close such a wait cycle backs off instead: it
+    /// fails with `IocError::DependencyCycle`, releasing its locks, and `instantiate_bean`
+    /// retries once the other thread is done.
+    fn create_singleton(
+        &self,
+        provider_ref: &BeanProviderRef,
+        def: &BeanDefinition,
+    ) -> Result<BeanInstance, IocError> {
+        let name = def.bean_name.as_str();
+        let thread = std::thread::current().id();
+        let creation_lock = {
+            let mut creation_locks = self.creation_locks.lock();
+            let creation_lock = creation_locks
+                .locks
+                .entry(name.to_string())
+                .or_default()
+                .clone();
+            // A thread never waits on a lock it holds itself: re-entering the same bean on one
+            // thread is rejected as a cycle by `enter_creation` before reaching this point.
+            if creation_locks.would_deadlock(thread, name) {
+                drop(creation_locks);
+                return Err(self.back_off_from(name, creation_lock));
+            }
+            creation_locks.waiting.insert(thread, name.to_string());
+            creation_lock
+        };
+        let _creating = creation_lock.lock();
+        let _owned = {
+            let mut creation_locks = self.creation_locks.lock();
+            creation_locks.waiting.remove(&thread);
+            creation_locks.owners.insert(name.to_string(), thread);
+            CreationOwnership {
+                locks: &self.creation_locks,
                 name,
-                creating_guard.iter()
+            }
+        };
+
+        if let Some(existing_instance) = self.singleton_instances.read().get(name) {
+            // Another thread created the bean while we were waiting for the lock.
+            log::trace!(
+                "Bean '{}' was created by another thread. Using cached instance.",
+                name
             ); // Added logging
-               // Write lock is released here
+            return Ok(existing_instance.clone());
         }
 
-        bean_instance_result // Return the result (Ok(BeanInstance) or Err)
+        let instance = self.construct_bean(provider_ref, def)?;
+        self.cache_singleton(def, instance.clone());
+        Ok(instance)
+    }
+
+    /// Async counterpart of `create_singleton` for beans that require async creation.
+    async fn create_singleton_async(&self, def: &BeanDefinition) -> Result<BeanInstance, IocError> {
+        let name = def.bean_name.as_str();
+        let creation_lock = self
+            .async_creation_locks
+            .lock()
+            .entry(name.to_string())
+            .or_default()
+            .clone();
+        let _creating = creation_lock.lock().await;
+
+        if let Some(existing_instance) = self.singleton_instances.read().get(name).cloned() {
+            log::trace!(
+                "Bean '{}' was created by another task. Using cached instance.",
+                name
+            );
+            return Ok(existing_instance);
+        }
+
+        let provider_ref = self.provider_ref()?;
+        let instance = self.construct_bean_async(&provider_ref, def).await?;
+        // Synchronous lookups never finish creating such a bean, so none can have cached it.
+        self.cache_singleton(def, instance.clone());
+        Ok(instance)
+    }
+
+    /// Records that this thread must wait for `contended` before retrying its outermost
+    /// creation, and returns the error unwinding the creations in progress on this thread.
+    fn back_off_from(&self, name: &str, contended: Arc<Mutex<()>>) -> IocError {
+        let container_id = self as *const Self as usize;
+        CREATION_RETRY.with(|retry| *retry.borrow_mut() = Some((container_id, contended)));
+        let mut cycle_path: Vec<String> = CREATION_CHAIN.with(|chain| {
+            chain
+                .borrow()
+                .iter()
+                .filter(|(id, _)| *id == container_id)
+                .map(|(_, creating)| creating.clone())
+                .collect()
+        });
+        // `enter_creation` has already pushed `name`; the thread holding it waits for this one.
+        cycle_path.retain(|creating| creating != name);
+        cycle_path.insert(0, name.to_string());
+        cycle_path.push(name.to_string());
+        log::debug!(
+            "Backing off from bean '{}', held by a thread waiting for this one. Path: {}",
+            name,
+            cycle_path.join(" -> ")
+        );
+        IocError::DependencyCycle(name.to_string(), cycle_path)
+    }
+
+    fn cache_singleton(&self, def: &BeanDefinition, instance: BeanInstance) {
+        let name = def.bean_name.as_str();
+        self.singleton_instances
+            .write()
+            .insert(name.to_string(), instance.clone());
+        self.singleton_created_at
+            .write()
+            .insert(name.to_string(), SystemTime::now());
+        for listener in summer_core::inventory::iter::<EventListenerMetadata>() {
+            if (listener.bean_type_id)() == def.bean_type_id {
+                self.event_multicaster
+                    .add_bean_listener(name, instance.clone(), listener);
+            }
+        }
+        if self.is_frozen() {
+            self.add_to_frozen_snapshot(def, &instance);
+        }
+        if let Some(callback) = def.pre_destroy {
+            // Dependencies finish construction first, so creation order is dependency order.
+            self.shutdown_commands.lock().push(PreDestroyCommand::new(
+                name.to_string(),
+                instance,
+                callback,
+            ));
+        }
+        log::trace!("Bean '{}' successfully cached.", name); // Added logging
+    }
+
+    /// Seals the initialized container: bean definitions can no longer be registered, and
+    /// lookups of existing singletons by name or unqualified type are served from an immutable
+    /// snapshot without taking any lock. Singletons created later (e.g. lazy ones) are added by
+    /// atomically swapping in a new snapshot. Closing the container unfreezes it.
+    pub fn freeze(&self) -> Result<(), IocError> {
+        self.ensure_initialized()?;
+        self.refresh_frozen_snapshot();
+        log::info!("Summer IOC container frozen.");
+        Ok(())
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen.load().is_some()
+    }
+
+    /// Looks `lookup` up in the frozen snapshot, if the container is frozen.
+    fn frozen_bean(
+        &self,
+        lookup: impl FnOnce(&FrozenBeans) -> Option<&BeanInstance>,
+    ) -> Option<BeanInstance> {
+        self.frozen.load().as_deref().and_then(lookup).cloned()
+    }
+
+    /// Builds the snapshot from the cached singletons.
+    fn refresh_frozen_snapshot(&self) {
+        let by_name = self.singleton_instances.read().clone();
+        let type_ids: Vec<TypeId> = self.beans_by_type.read().keys().copied().collect();
+        let mut by_type = HashMap::new();
+        for type_id in type_ids {
+            let Ok(name) = self.resolve_bean_name(self.type_info(type_id), None) else {
+                continue;
+            };
+            let Some(instance) = by_name.get(&name) else {
+                continue;
+            };
+            let view = match self.definitions.read().get(&name) {
+                Some(def) if def.bean_type_id == type_id => Some(instance.clone()),
+                Some(def) => def
+                    .caster_for(type_id)
+                    .and_then(|cast| cast(instance.clone())),
+                None => None,
+            };
+            if let Some(view) = view {
+                by_type.insert(type_id, view);
+            }
+        }
+        log::debug!(
+            "Frozen snapshot holds {} singletons under {} types.",
+            by_name.len(),
+            by_type.len()
+        );
+        self.frozen
+            .store(Some(Arc::new(FrozenBeans { by_type, by_name })));
+    }
+
+    /// Adds the singleton `def.bean_name`, created after freezing, to the snapshot: under its
+    /// name, and under each of its types whose unqualified lookup resolves to it.
+    fn add_to_frozen_snapshot(&self, def: &BeanDefinition, instance: &BeanInstance) {
+        let name = &def.bean_name;
+        let type_ids =
+            std::iter::once(def.bean_type_id).chain(def.provides.iter().map(|p| p.type_id));
+        let mut views = Vec::new();
+        for type_id in type_ids {
+            match self.resolve_bean_name(self.type_info(type_id), None) {
+                Ok(resolved) if resolved == *name => {}
+                _ => continue,
+            }
+            let view = if type_id == def.bean_type_id {
+                Some(instance.clone())
+            } else {
+                def.caster_for(type_id)
+                    .and_then(|cast| cast(instance.clone()))
+            };
+            if let Some(view) = view {
+                views.push((type_id, view));
+            }
+        }
+        // Retried if another singleton is added concurrently, so neither entry is lost.
+        self.frozen.rcu(|current| {
+            current.as_ref().map(|frozen| {
+                let mut frozen = FrozenBeans::clone(frozen);
+                frozen.by_name.insert(name.clone(), instance.clone());
+                frozen.by_type.extend(views.iter().cloned());
+                Arc::new(frozen)
+            })
+        });
+    }
+
+    /// Retrieves a bean of type T, awaiting the async constructors (`async fn new`) of the bean
+    /// and of the beans it depends on instead of blocking on them.
+    ///
+    /// Only declared dependencies (`#[constructor]` parameters, `#[autowired]` fields,
+    /// `BeanDefinition::with_dependency`) are built asynchronously ahead of the bean itself.
+    pub async fn get_bean_async<T: Any + Send + Sync>(&self) -> Result<Arc<T>, IocError> {
+        self.ensure_initialized()?;
+        // Beans that only the parent defines are left to the synchronous lookup.
+        if let Ok(name) = self.resolve_bean_name(TypeInfo::of::<T>(), None) {
+            if let Some(instance) = self.prepare_bean_async(&name).await? {
+                return instance
+                    .downcast::<T>()
+                    .map_err(|instance| IocError::TypeMismatchError {
+                        bean_name: name,
+                        requested: TypeInfo::of::<T>(),
+                        stored: self.type_info((*instance).type_id()),
+                    });
+            }
+        }
+        self.get_bean::<T>()
+    }
+
+    /// Builds the declared dependencies of `name` and, for a singleton, the bean itself,
+    /// awaiting async constructors, so that a following synchronous lookup only hits the cache.
+    ///
+    /// A prototype that requires async creation is built as well and returned, since a
+    /// following lookup would fail to build another one.
+    fn prepare_bean_async<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> BoxFuture<'a, Result<Option<BeanInstance>, IocError>> {
+        Box::pin(async move {
+            self.ensure_initialized()?;
+            if self.singleton_instances.read().contains_key(name) {
+                return Ok(None);
+            }
+            let Some(def) = self.definitions.read().get(name).cloned() else {
+                return Ok(None);
+            };
+
+            // Lazy dependencies are resolved after construction and may close a cycle.
+            for dependency in def
+                .dependencies
+                .iter()
+                .filter(|dependency| !dependency.lazy)
+            {
+                let names = if dependency.collection {
+                    self.bean_names_for_type(dependency.type_id)
+                } else {
+                    // Unresolvable dependencies are reported by the constructor itself.
+                    let dependency_type = self.type_info(dependency.type_id);
+                    self.resolve_bean_name(dependency_type, dependency.qualifier.as_deref())
+                        .into_iter()
+                        .collect()
+                };
+                for dependency_name in names {
+                    self.prepare_bean_async(&dependency_name)
+                        .await
+                        .map_err(|e| {
+                            let dependency_type = self.type_info(dependency.type_id);
+                            instantiation_error(name, into_dependency_error(dependency_type, e))
+                        })?;
+                }
+            }
+
+            match &def.scope {
+                BeanScope::Singleton if def.requires_async_creation() => {
+                    self.create_singleton_async(&def).await?;
+                }
+                BeanScope::Singleton => {
+                    self.get_bean_by_name_any(name)?;
+                }
+                BeanScope::Prototype if def.requires_async_creation() => {
+                    let provider_ref = self.provider_ref()?;
+                    let instance = self.construct_bean_async(&provider_ref, &def).await?;
+                    return Ok(Some(instance));
+                }
+                // Other scopes build a new instance on the lookup itself.
+                _ => {}
+            }
+            Ok(None)
+        })
     }
 
     /// Retrieves a bean instance by its type T.
     pub fn get_bean<T: Any + Send + Sync>(&self) -> Result<Arc<T>, IocError> {
+        self.get_concrete_bean::<T>(None)
+    }
+
+    /// Retrieves the bean identified by a typed key, e.g. `container.get(UserService::BEAN)`.
+    /// The key fixes both the bean name and its type, so neither can be misspelled.
+    pub fn get<T: Bean>(&self, key: BeanKey<T>) -> Result<Arc<T>, IocError> {
+        self.get_bean_by_name::<T>(key.name())
+    }
+
+    /// Retrieves the bean of type T selected by `qualifier` (a declared qualifier or bean name).
+    pub fn get_qualified_bean<T: Any + Send + Sync>(
+        &self,
+        qualifier: &str,
+    ) -> Result<Arc<T>, IocError> {
+        self.get_concrete_bean::<T>(Some(qualifier))
+    }
+
+    /// Retrieves every bean of type T, ordered by `#[order(n)]` and then bean name.
+    /// Returns an empty list when there are none.
+    pub fn get_beans_of_type<T: Any + Send + Sync>(&self) -> Result<Vec<Arc<T>>, IocError> {
+        Ok(self
+            .get_bean_map_entries::<T>()?
+            .into_iter()
+            .map(|(_, bean)| bean)
+            .collect())
+    }
+
+    /// Retrieves every provider of the trait object `T`, in the same order as `get_beans_of_type`.
+    pub fn get_beans_of_type_dyn<T: ?Sized + Send + Sync + 'static>(
+        &self,
+    ) -> Result<Vec<Arc<T>>, IocError> {
+        Ok(self
+            .get_view_map_entries::<T>()?
+            .into_iter()
+            .map(|(_, bean)| bean)
+            .collect())
+    }
+
+    /// Retrieves every bean of type T, keyed by bean name.
+    pub fn get_bean_map<T: Any + Send + Sync>(&self) -> Result<HashMap<String, Arc<T>>, IocError> {
+        Ok(self.get_bean_map_entries::<T>()?.into_iter().collect())
+    }
+
+    /// Retrieves every provider of the trait object `T`, keyed by bean name.
+    pub fn get_bean_map_dyn<T: ?Sized + Send + Sync + 'static>(
+        &self,
+    ) -> Result<HashMap<String, Arc<T>>, IocError> {
+        Ok(self.get_view_map_entries::<T>()?.into_iter().collect())
+    }
+
+    fn get_bean_map_entries<T: Any + Send + Sync>(
+        &self,
+    ) -> Result<Vec<(String, Arc<T>)>, IocError> {
+        self.ensure_initialized()?;
         let type_id = TypeId::of::<T>();
-        // Find potential bean names for this type
-        let bean_names = {
+        self.find_beans(type_id)?
+            .into_iter()
+            .map(|(name, instance)| match instance.downcast::<T>() {
+                Ok(bean) => Ok((name, bean)),
+                Err(instance) => Err(IocError::TypeMismatchError {
+                    bean_name: name,
+                    requested: TypeInfo::of::<T>(),
+                    stored: self.type_info((*instance).type_id()),
+                }),
+            })
+            .collect()
+    }
+
+    fn get_view_map_entries<T: ?Sized + Send + Sync + 'static>(
+        &self,
+    ) -> Result<Vec<(String, Arc<T>)>, IocError> {
+        self.ensure_initialized()?;
+        let type_id = TypeId::of::<T>();
+        self.find_beans(type_id)?
+            .into_iter()
+            .map(|(name, view)| match view.downcast::<Arc<T>>() {
+                // Trait-object views are erased as Arc<Arc<dyn Trait>> (see summer_core::BeanCaster).
+                Ok(view) => Ok((name, Arc::clone(&*view))),
+                Err(view) => Err(IocError::TypeMismatchError {
+                    bean_name: name,
+                    requested: TypeInfo::of::<T>(),
+                    stored: self.type_info((*view).type_id()),
+                }),
+            })
+            .collect()
+    }
+
+    fn ensure_initialized(&self) -> Result<(), IocError> {
+        if *self.initialized.read() {
+            Ok(())
+        } else {
+            Err(IocError::ContainerNotInitialized)
+        }
+    }
+
+    /// Retrieves the bean registered as provider of the trait object `T`,
+    /// e.g. `container.get_bean_dyn::<dyn UserRepository>()`.
+    pub fn get_bean_dyn<T: ?Sized + Send + Sync + 'static>(&self) -> Result<Arc<T>, IocError> {
+        self.get_bean_view::<T>(None)
+    }
+
+    /// Retrieves the provider of the trait object `T` selected by `qualifier`.
+    pub fn get_qualified_bean_dyn<T: ?Sized + Send + Sync + 'static>(
+        &self,
+        qualifier: &str,
+    ) -> Result<Arc<T>, IocError> {
+        self.get_bean_view::<T>(Some(qualifier))
+    }
+
+    fn get_concrete_bean<T: Any + Send + Sync>(
+        &self,
+        qualifier: Option<&str>,
+    ) -> Result<Arc<T>, IocError> {
+        if qualifier.is_none() {
+            let frozen = self.frozen_bean(|frozen| frozen.by_type.get(&TypeId::of::<T>()));
+            if let Some(Ok(bean)) = frozen.map(|instance| instance.downcast::<T>()) {
+                return Ok(bean);
+            }
+        }
+        self.ensure_initialized()?;
+        let (name, instance) = self.find_bean(TypeInfo::of::<T>(), qualifier)?;
+        instance
+            .downcast::<T>()
+            .map_err(|instance| IocError::TypeMismatchError {
+                bean_name: name.unwrap_or_else(|| std::any::type_name::<T>().to_string()),
+                requested: TypeInfo::of::<T>(),
+                stored: self.type_info((*instance).type_id()),
+            })
+    }
+
+    fn get_bean_view<T: ?Sized + Send + Sync + 'static>(
+        &self,
+        qualifier: Option<&str>,
+    ) -> Result<Arc<T>, IocError> {
+        if qualifier.is_none() {
+            let frozen = self.frozen_bean(|frozen| frozen.by_type.get(&TypeId::of::<T>()));
+            if let Some(Ok(view)) = frozen.map(|view| view.downcast::<Arc<T>>()) {
+                return Ok(Arc::clone(&*view));
+            }
+        }
+        self.ensure_initialized()?;
+        let (name, view) = self.find_bean(TypeInfo::of::<T>(), qualifier)?;
+
+        // Trait-object views are erased as Arc<Arc<dyn Trait>> (see summer_core::BeanCaster).
+        view.downcast::<Arc<T>>()
+            .map(|view| Arc::clone(&*view))
+            .map_err(|view| IocError::TypeMismatchError {
+                bean_name: name.unwrap_or_else(|| std::any::type_name::<T>().to_string()),
+                requested: TypeInfo::of::<T>(),
+                stored: self.type_info((*view).type_id()),
+            })
+    }
+
+    /// Finds the instance registered for `bean_type` (the bean itself, or its view for a
+    /// provided type), together with its bean name when it is defined locally. Falls back to
+    /// the parent container when no local bean matches.
+    fn find_bean(
+        &self,
+        bean_type: TypeInfo,
+        qualifier: Option<&str>,
+    ) -> Result<(Option<String>, BeanInstance), IocError> {
+        let type_id = bean_type.id();
+        match (self.resolve_bean_name(bean_type, qualifier), &self.parent) {
+            (Ok(name), _) => {
+                let instance = self.get_bean_as_type(&name, type_id)?;
+                Ok((Some(name), instance))
+            }
+            (
+                Err(IocError::BeanNotFoundByType { .. } | IocError::BeanNotFoundByQualifier { .. }),
+                Some(parent),
+            ) => {
+                log::trace!("No local bean for type {}, asking the parent.", bean_type);
+                let instance = match qualifier {
+                    Some(qualifier) => parent.get_qualified_bean_by_typeid(type_id, qualifier),
+                    None => parent.get_bean_by_typeid(type_id),
+                }
+                .map_err(|e| from_parent_error(e.with_type_name(bean_type)))?;
+                Ok((None, instance))
+            }
+            (Err(e), _) => Err(e),
+        }
+    }
+
+    /// Shared implementation of the `BeanProvider` lookups handed to constructors.
+    fn provide_bean(
+        &self,
+        type_id: TypeId,
+        qualifier: Option<&str>,
+    ) -> Result<BeanInstance, ConstructorError> {
+        if qualifier.is_none() {
+            if let Some(instance) = self.frozen_bean(|frozen| frozen.by_type.get(&type_id)) {
+                return Ok(instance);
+            }
+        }
+        // Ensure container is initialized before attempting to get beans.
+        if !*self.initialized.read() {
+            return Err(ConstructorError::ContainerNotInitialized);
+        }
+
+        // Resolve the single candidate, then fetch the instance (or the requested view of it).
+        let bean_type = self.type_info(type_id);
+        self.find_bean(bean_type, qualifier)
+            .map(|(_, instance)| instance)
+            .map_err(|e| into_dependency_error(bean_type, e))
+    }
+
+    /// Names of the local beans registered under `type_id`, sorted by order, then bean name.
+    fn bean_names_for_type(&self, type_id: TypeId) -> Vec<String> {
+        let names = self
+            .beans_by_type
+            .read()
+            .get(&type_id)
+            .cloned()
+            .unwrap_or_default();
+        // Read definitions only after releasing beans_by_type (see `resolve_bean_name`).
+        let definitions_guard = self.definitions.read();
+        let mut ordered: Vec<(i32, String)> = names
+            .into_iter()
+            .filter_map(|name| {
+                let order = definitions_guard.get(&name)?.order.unwrap_or(i32::MAX);
+                Some((order, name))
+            })
+            .collect();
+        ordered.sort();
+        ordered.into_iter().map(|(_, name)| name).collect()
+    }
+
+    /// Every bean registered for `type_id` (instances or views) with its name, in bean order.
+    /// Beans from the parent container follow the local ones, unless shadowed by name.
+    fn find_beans(&self, type_id: TypeId) -> Result<Vec<(String, BeanInstance)>, IocError> {
+        let mut beans = self
+            .bean_names_for_type(type_id)
+            .into_iter()
+            .map(|name| {
+                let instance = self.get_bean_as_type(&name, type_id)?;
+                Ok((name, instance))
+            })
+            .collect::<Result<Vec<_>, IocError>>()?;
+
+        if let Some(parent) = &self.parent {
+            let inherited = parent
+                .get_beans_by_typeid(type_id)
+                .map_err(from_parent_error)?;
+            let definitions_guard = self.definitions.read();
+            beans.extend(
+                inherited
+                    .into_iter()
+                    .filter(|(name, _)| !definitions_guard.contains_key(name)),
+            );
+        }
+        Ok(beans)
+    }
+
+    /// Finds the name of the bean registered under `bean_type` that should be injected.
+    ///
+    /// Candidates are first narrowed by `qualifier`, if any. A single remaining candidate wins;
+    /// among several, exactly one of them must be marked primary.
+    fn resolve_bean_name(
+        &self,
+        bean_type: TypeInfo,
+        qualifier: Option<&str>,
+    ) -> Result<String, IocError> {
+        let names = {
             let beans_by_type_read = self.beans_by_type.read();
-            beans_by_type_read.get(&type_id).cloned() // Clone the Vec<String>
+            beans_by_type_read
+                .get(&bean_type.id())
+                .cloned()
+                .unwrap_or_default()
         };
+        if names.is_empty() {
+            return Err(IocError::BeanNotFoundByType {
+                bean_type,
+                suggestions: self.type_suggestions(bean_type),
+            });
+        }
 
-        match bean_names {
-            Some(names) => {
-                if names.len() == 1 {
-                    // If only one bean of this type, get it by name
-                    self.get_bean_by_name::<T>(&names[0])
-                } else if names.is_empty() {
-                    // Should not happen if TypeId is in the map, but handle defensively
-                    Err(IocError::BeanNotFoundByType(type_id))
-                } else {
-                    // Multiple beans found for the type
-                    Err(IocError::MultipleBeansFound(type_id))
-                }
+        // Read definitions only after releasing beans_by_type, matching the lock order of
+        // registration (definitions before beans_by_type).
+        let definitions_guard = self.definitions.read();
+        let mut candidates: Vec<&BeanDefinition> = names
+            .iter()
+            .filter_map(|name| definitions_guard.get(name))
+            .collect();
+        if let Some(qualifier) = qualifier {
+            candidates.retain(|def| def.matches_qualifier(qualifier));
+            if candidates.is_empty() {
+                return Err(IocError::BeanNotFoundByQualifier {
+                    bean_type,
+                    qualifier: qualifier.to_string(),
+                    suggestions: qualifier_suggestions(qualifier, &names, &definitions_guard),
+                });
             }
-            None => {
-                // No bean found for the type
-                Err(IocError::BeanNotFoundByType(type_id))
+        }
+
+        match candidates.as_slice() {
+            [] => Err(IocError::BeanNotFoundByType {
+                bean_type,
+                suggestions: Suggestions::default(),
+            }),
+            [single] => Ok(single.bean_name.clone()),
+            _ => {
+                let mut primaries = candidates.iter().filter(|def| def.is_primary);
+                match (primaries.next(), primaries.next()) {
+                    (Some(primary), None) => Ok(primary.bean_name.clone()),
+                    // No primary, or more than one: still ambiguous.
+                    _ => Err(IocError::MultipleBeansFound(bean_type)),
+                }
             }
         }
     }
+
+    /// Retrieves the bean `name` as the instance registered for `type_id`: the bean itself
+    /// for its concrete type, or the view produced by its caster for a provided type.
+    fn get_bean_as_type(&self, name: &str, type_id: TypeId) -> Result<BeanInstance, IocError> {
+        let instance = self.get_bean_by_name_any(name)?;
+
+        // The read guard is a temporary, released before the not-found error reads the
+        // definitions again: re-taking a fair lock could deadlock behind a queued writer.
+        let definition = self
+            .definitions
+            .read()
+            .get(name)
+            .map(|def| (def.bean_type_id, def.caster_for(type_id)));
+        let caster = match definition {
+            Some((bean_type_id, _)) if bean_type_id == type_id => return Ok(instance),
+            Some((_, caster)) => caster,
+            None => return Err(self.bean_not_found_by_name(name)),
+        };
+
+        let stored = (*instance).type_id();
+        caster
+            .and_then(|cast| cast(instance))
+            .ok_or_else(|| IocError::TypeMismatchError {
+                bean_name: name.to_string(),
+                requested: self.type_info(type_id),
+                stored: self.type_info(stored),
+            })
+    }
+
+    fn register_type_name(&self, type_id: TypeId, type_name: &'static str) {
+        self.type_names.write().entry(type_id).or_insert(type_name);
+    }
+
+    /// `type_id` with its Rust type path, if any bean, provided type or declared dependency
+    /// has registered it.
+    fn type_info(&self, type_id: TypeId) -> TypeInfo {
+        match self.type_names.read().get(&type_id) {
+            Some(type_name) => TypeInfo::new(type_id, type_name),
+            None => TypeInfo::unnamed(type_id),
+        }
+    }
+
+    fn bean_not_found_by_name(&self, name: &str) -> IocError {
+        let definitions_guard = self.definitions.read();
+        let suggestions = similar(name, definitions_guard.keys().map(String::as_str))
+            .into_iter()
+            .map(|candidate| format!("'{}'", candidate))
+            .collect::<Vec<_>>();
+        IocError::BeanNotFoundByName {
+            name: name.to_string(),
+            suggestions: suggestions.into(),
+        }
+    }
+
+    /// Beans registered under a type whose name looks like `bean_type`'s, e.g. the same struct
+    /// from another module, or a trait object the requested type was meant to be.
+    fn type_suggestions(&self, bean_type: TypeInfo) -> Suggestions {
+        let Some(requested) = bean_type.name() else {
+            return Suggestions::default();
+        };
+        let type_names = self.type_names.read().clone();
+        let candidates: Vec<(&'static str, Vec<String>)> = self
+            .beans_by_type
+            .read()
+            .iter()
+            .filter(|(type_id, names)| **type_id != bean_type.id() && !names.is_empty())
+            .filter_map(|(type_id, names)| Some((*type_names.get(type_id)?, names.clone())))
+            .collect();
+        let similar_types = similar(
+            short_type_name(requested),
+            candidates
+                .iter()
+                .map(|(type_name, _)| short_type_name(type_name)),
+        );
+        let mut suggestions: Vec<String> = candidates
+            .iter()
+            .filter(|(type_name, _)| similar_types.contains(&short_type_name(type_name)))
+            .flat_map(|(type_name, names)| {
+                names
+                    .iter()
+                    .map(move |name| format!("'{}' of type {}", name, type_name))
+            })
+            .collect();
+        suggestions.sort();
+        suggestions.truncate(3);
+        suggestions.into()
+    }
+}
+
+/// For a qualifier that matches none of the beans of the requested type: the bean names and
+/// qualifiers closest to it, or else every bean of the type.
+fn qualifier_suggestions(
+    qualifier: &str,
+    bean_names: &[String],
+    definitions: &HashMap<String, BeanDefinition>,
+) -> Suggestions {
+    let known: BTreeSet<&str> = bean_names
+        .iter()
+        .filter_map(|name| definitions.get(name))
+        .flat_map(|def| {
+            std::iter::once(def.bean_name.as_str()).chain(def.qualifiers.iter().map(String::as_str))
+        })
+        .collect();
+    let mut suggestions = similar(qualifier, known.iter().copied());
+    if suggestions.is_empty() {
+        suggestions = bean_names.iter().map(String::as_str).take(3).collect();
+    }
+    suggestions
+        .into_iter()
+        .map(|candidate| format!("'{}'", candidate))
+        .collect::<Vec<_>>()
+        .into()
+}
+
+/// Adds to `into` the singletons `bean_name` needs at construction: its non-lazy dependencies,
+/// looking through dependencies of other scopes, which are created along with it.
+fn collect_startup_dependencies(
+    graph: &DependencyGraph,
+    singletons: &HashSet<String>,
+    bean_name: &str,
+    into: &mut BTreeSet<String>,
+) {
+    // The graph has no cycles through non-lazy edges (checked in `initialize`).
+    for edge in graph
+        .edges
+        .iter()
+        .filter(|edge| edge.from == bean_name && !edge.lazy)
+    {
+        if singletons.contains(&edge.to) {
+            into.insert(edge.to.clone());
+        } else {
+            collect_startup_dependencies(graph, singletons, &edge.to, into);
+        }
+    }
+}
+
+/// Wraps the error of `bean_name`'s constructor, extending the resolution path of the failed
+/// dependency if that is what broke the bean.
+fn instantiation_error(bean_name: &str, error: ConstructorError) -> IocError {
+    let mut path = vec![bean_name.to_string()];
+    path.extend(
+        std::error::Error::source(&error)
+            .and_then(|source| source.downcast_ref::<IocError>())
+            .map(|nested| nested.resolution_path().to_vec())
+            .unwrap_or_default(),
+    );
+    IocError::InstantiationError {
+        bean_name: bean_name.to_string(),
+        path,
+        source: error,
+    }
+}
+
+fn post_construct_error(bean_name: &str, error: ConstructorError) -> IocError {
+    log::error!(
+        "Post-construct callback failed for bean '{}': {}",
+        bean_name,
+        error
+    );
+    IocError::LifecycleCallbackError {
+        bean_name: bean_name.to_string(),
+        callback: "post_construct",
+        source: error,
+    }
+}
+
+/// Converts an error raised while providing a dependency of type `bean_type` to a constructor.
+fn into_dependency_error(bean_type: TypeInfo, error: IocError) -> ConstructorError {
+    match error {
+        // Lookup errors keep their meaning, so `Option<Arc<T>>` injection still sees
+        // a missing bean as absent.
+        IocError::BeanNotFoundByType { .. }
+        | IocError::MultipleBeansFound(_)
+        | IocError::BeanNotFoundByQualifier { .. }
+        | IocError::ContainerNotInitialized
+        | IocError::ParentLookupError(_) => into_constructor_error(error),
+        // Keep the container's error as the source so the root cause is not lost.
+        other => ConstructorError::DependencyFailed {
+            bean_type,
+            source: Box::new(other),
+        },
+    }
+}
+
+/// Converts a container error for callers that only understand `ConstructorError`.
+fn into_constructor_error(error: IocError) -> ConstructorError {
+    match error {
+        IocError::BeanNotFoundByType {
+            bean_type,
+            suggestions,
+        } => ConstructorError::BeanNotFoundByType {
+            bean_type,
+            suggestions,
+        },
+        IocError::BeanNotFoundByName { name, suggestions } => {
+            ConstructorError::BeanNotFoundByName { name, suggestions }
+        }
+        IocError::MultipleBeansFound(bean_type) => ConstructorError::MultipleBeansFound(bean_type),
+        IocError::BeanNotFoundByQualifier {
+            bean_type,
+            qualifier,
+            suggestions,
+        } => ConstructorError::BeanNotFoundByQualifier {
+            bean_type,
+            qualifier,
+            suggestions,
+        },
+        IocError::ContainerNotInitialized => ConstructorError::ContainerNotInitialized,
+        IocError::ParentLookupError(source) => source,
+        other => ConstructorError::Other(Box::new(other)),
+    }
+}
+
+/// Converts an error from the parent container's `BeanProvider` back into an `IocError`.
+fn from_parent_error(error: ConstructorError) -> IocError {
+    match error {
+        ConstructorError::BeanNotFoundByType {
+            bean_type,
+            suggestions,
+        } => IocError::BeanNotFoundByType {
+            bean_type,
+            suggestions,
+        },
+        ConstructorError::BeanNotFoundByName { name, suggestions } => {
+            IocError::BeanNotFoundByName { name, suggestions }
+        }
+        ConstructorError::MultipleBeansFound(bean_type) => IocError::MultipleBeansFound(bean_type),
+        ConstructorError::BeanNotFoundByQualifier {
+            bean_type,
+            qualifier,
+            suggestions,
+        } => IocError::BeanNotFoundByQualifier {
+            bean_type,
+            qualifier,
+            suggestions,
+        },
+        other => IocError::ParentLookupError(other),
+    }
 }
@@ -1,26 +1,280 @@
-use std::any::TypeId;
+use crate::condition::BeanCondition;
+use crate::scope::BeanScope;
+use std::any::{Any, TypeId};
+use std::collections::HashSet;
+use std::future::Future;
+use std::sync::Arc;
 // Import the type aliases from summer_core
-use summer_core::BeanConstructor;
+use summer_core::{
+    BeanCaster, BeanConstructorFuture, BeanConstructorResult, BeanInstance, BeanNameAware,
+    BeanNameAwareCallback, BeanProviderRef, ConstructorError, ContainerAware,
+    ContainerAwareCallback, LifecycleCallback,
+};
+
+/// Type name of definitions created with [`BeanDefinition::new`] without a type name.
+pub const UNKNOWN_TYPE_NAME: &str = "<unknown>";
+
+/// The constructor stored in a definition: a macro-generated `BeanConstructor` or a
+/// capturing closure registered programmatically.
+pub type ConstructorFn = Arc<dyn Fn(BeanProviderRef) -> BeanConstructorResult + Send + Sync>;
+
+/// The constructor of a bean that must be awaited: a macro-generated `AsyncBeanConstructor`
+/// or an async closure registered programmatically.
+pub type AsyncConstructorFn = Arc<dyn Fn(BeanProviderRef) -> BeanConstructorFuture + Send + Sync>;
+
+/// An additional type (usually a trait object) under which a bean can be resolved.
+#[derive(Clone)]
+pub struct ProvidedType {
+    pub type_id: TypeId,
+    /// Converts the bean's concrete instance into the erased view for `type_id`.
+    pub caster: BeanCaster,
+}
+
+/// A dependency a bean declares, typically a `#[constructor]` parameter or `#[autowired]` field.
+#[derive(Clone, Debug)]
+pub struct BeanDependency {
+    pub type_id: TypeId,
+    pub type_name: String,
+    pub qualifier: Option<String>,
+    /// Whether the bean can be built without it (`Option<Arc<T>>`).
+    pub optional: bool,
+    /// Whether every bean of the type is injected (`Vec<Arc<T>>`, `HashMap<String, Arc<T>>`).
+    pub collection: bool,
+    /// Whether it is resolved after construction (`Lazy<T>`, `ObjectProvider<T>`).
+    pub lazy: bool,
+}
+
+/// What happens when a bean definition is registered under a name that is already taken.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BeanOverridePolicy {
+    /// Registration fails with `IocError::BeanAlreadyExists` (the default).
+    #[default]
+    Deny,
+    /// The new definition silently replaces the old one.
+    Allow,
+    /// The new definition replaces the old one and a warning is logged.
+    AllowWithWarning,
+}
 
 /// Represents the definition of a bean within the IoC container.
 #[derive(Clone)]
 pub struct BeanDefinition {
     pub bean_name: String,
     pub bean_type_id: TypeId,
-    // Use the type alias from summer_core
-    pub constructor: BeanConstructor,
+    /// The Rust type path of `bean_type_id`, for reports and diagnostics.
+    pub bean_type_name: &'static str,
+    pub constructor: ConstructorFn,
+    /// Awaited by async lookups (`IocContainer::get_bean_async`, `initialize_async`); the
+    /// container never calls `constructor` of such beans.
+    pub async_constructor: Option<AsyncConstructorFn>,
+    pub provides: Vec<ProvidedType>,
+    /// Wins unqualified lookups when several beans share a type (like Spring's `@Primary`).
+    pub is_primary: bool,
+    /// Names injection points can use to select this bean (like Spring's `@Qualifier`).
+    pub qualifiers: HashSet<String>,
+    pub scope: BeanScope,
+    /// Runs after construction, before the bean is handed out (like `@PostConstruct`).
+    pub post_construct: Option<LifecycleCallback>,
+    /// Runs for singletons when the container is closed (like `@PreDestroy`).
+    pub pre_destroy: Option<LifecycleCallback>,
+    /// Dependencies used to build the dependency graph; not needed for construction itself.
+    pub dependencies: Vec<BeanDependency>,
+    /// Position among beans of the same type in `get_beans_of_type` and collection injection;
+    /// lower comes first, unordered beans last (like Spring's `@Order`).
+    pub order: Option<i32>,
+    /// Excludes a singleton from eager instantiation at startup (like Spring's `@Lazy`).
+    pub lazy: bool,
+    /// Hands the bean its name right after construction (like Spring's `BeanNameAware`).
+    pub bean_name_aware: Option<BeanNameAwareCallback>,
+    /// Hands the bean a weak container handle right after construction.
+    pub container_aware: Option<ContainerAwareCallback>,
+    /// Conditions that must all hold for `initialize` to keep the bean.
+    pub conditions: Vec<BeanCondition>,
 }
 
 impl BeanDefinition {
     pub fn new(
         bean_name: String,
         bean_type_id: TypeId,
-        constructor: BeanConstructor, // Use the alias here too
+        // A `BeanConstructor` fn or a capturing closure
+        constructor: impl Fn(BeanProviderRef) -> BeanConstructorResult + Send + Sync + 'static,
     ) -> Self {
         BeanDefinition {
             bean_name,
             bean_type_id,
-            constructor,
+            bean_type_name: UNKNOWN_TYPE_NAME,
+            constructor: Arc::new(constructor),
+            async_constructor: None,
+            provides: Vec::new(),
+            is_primary: false,
+            qualifiers: HashSet::new(),
+            scope: BeanScope::Singleton,
+            post_construct: None,
+            pre_destroy: None,
+            lazy: false,
+            order: None,
+            dependencies: Vec::new(),
+            bean_name_aware: None,
+            container_aware: None,
+            conditions: Vec::new(),
         }
     }
+
+    /// Definition for an already built instance, e.g. a connection pool created in `main`.
+    /// Every lookup of the singleton returns this instance.
+    pub fn for_instance<T: Any + Send + Sync>(
+        bean_name: impl Into<String>,
+        instance: Arc<T>,
+    ) -> Self {
+        let instance: BeanInstance = instance;
+        BeanDefinition::new(bean_name.into(), TypeId::of::<T>(), move |_| {
+            Ok(instance.clone())
+        })
+        .with_type_name(std::any::type_name::<T>())
+    }
+
+    /// Definition whose beans are built by `factory`, which can capture state and resolve
+    /// dependencies from the provider it is given.
+    pub fn for_factory<T, F>(bean_name: impl Into<String>, factory: F) -> Self
+    where
+        T: Any + Send + Sync,
+        F: Fn(&BeanProviderRef) -> Result<T, ConstructorError> + Send + Sync + 'static,
+    {
+        BeanDefinition::new(bean_name.into(), TypeId::of::<T>(), move |provider| {
+            factory(&provider).map(|bean| Arc::new(bean) as BeanInstance)
+        })
+        .with_type_name(std::any::type_name::<T>())
+    }
+
+    /// Definition for a bean whose constructor must be awaited, e.g. a database pool.
+    /// Async lookups await the constructor; synchronous lookups fail with
+    /// `IocError::AsyncConstructorRequired`.
+    pub fn new_async(
+        bean_name: String,
+        bean_type_id: TypeId,
+        constructor: impl Fn(BeanProviderRef) -> BeanConstructorFuture + Send + Sync + 'static,
+    ) -> Self {
+        let message = format!(
+            "bean '{}' has an async constructor and must be built asynchronously",
+            bean_name
+        );
+        let mut definition = BeanDefinition::new(bean_name, bean_type_id, move |_| {
+            Err(ConstructorError::BaseMsgError(message.clone()))
+        });
+        let constructor: AsyncConstructorFn = Arc::new(constructor);
+        definition.async_constructor = Some(constructor);
+        definition
+    }
+
+    /// Definition whose beans are built by the async `factory`, e.g. a client that has to
+    /// connect before it can be used.
+    pub fn for_async_factory<T, F, Fut>(bean_name: impl Into<String>, factory: F) -> Self
+    where
+        T: Any + Send + Sync,
+        F: Fn(BeanProviderRef) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<T, ConstructorError>> + Send + 'static,
+    {
+        BeanDefinition::new_async(bean_name.into(), TypeId::of::<T>(), move |provider| {
+            let bean = factory(provider);
+            Box::pin(async move { bean.await.map(|bean| Arc::new(bean) as BeanInstance) })
+        })
+        .with_type_name(std::any::type_name::<T>())
+    }
+
+    /// Whether creating the bean involves a future: an async constructor or an async
+    /// `#[post_construct]` callback. Only async lookups can create such beans.
+    pub(crate) fn requires_async_creation(&self) -> bool {
+        self.async_constructor.is_some()
+            || matches!(self.post_construct, Some(LifecycleCallback::Async(_)))
+    }
+
+    /// Sets the type path reported for the bean, normally `std::any::type_name` of its type.
+    pub fn with_type_name(mut self, type_name: &'static str) -> Self {
+        self.bean_type_name = type_name;
+        self
+    }
+
+    pub fn with_scope(mut self, scope: BeanScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    pub fn with_dependency(mut self, dependency: BeanDependency) -> Self {
+        self.dependencies.push(dependency);
+        self
+    }
+
+    pub fn with_order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn with_lazy(mut self, lazy: bool) -> Self {
+        self.lazy = lazy;
+        self
+    }
+
+    /// Whether the bean is created when the container starts rather than on first lookup.
+    pub fn is_eager_singleton(&self) -> bool {
+        self.scope == BeanScope::Singleton && !self.lazy
+    }
+
+    pub fn with_post_construct(mut self, callback: LifecycleCallback) -> Self {
+        self.post_construct = Some(callback);
+        self
+    }
+
+    pub fn with_pre_destroy(mut self, callback: LifecycleCallback) -> Self {
+        self.pre_destroy = Some(callback);
+        self
+    }
+
+    /// Passes the bean its name through [`BeanNameAware`]; `T` is the bean's concrete type.
+    pub fn with_bean_name_aware<T: BeanNameAware + Any>(mut self) -> Self {
+        self.bean_name_aware = Some(summer_core::notify_bean_name::<T>);
+        self
+    }
+
+    /// Passes the bean a container handle through [`ContainerAware`]; `T` is the bean's
+    /// concrete type.
+    pub fn with_container_aware<T: ContainerAware + Any>(mut self) -> Self {
+        self.container_aware = Some(summer_core::notify_container::<T>);
+        self
+    }
+
+    pub fn with_condition(mut self, condition: BeanCondition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    /// Marks the bean as the primary candidate for its types.
+    pub fn with_primary(mut self, is_primary: bool) -> Self {
+        self.is_primary = is_primary;
+        self
+    }
+
+    /// Adds a qualifier that selects this bean at injection points.
+    pub fn with_qualifier(mut self, qualifier: impl Into<String>) -> Self {
+        self.qualifiers.insert(qualifier.into());
+        self
+    }
+
+    /// A bean matches a qualifier if it declares it, or if the qualifier is its bean name.
+    pub fn matches_qualifier(&self, qualifier: &str) -> bool {
+        self.bean_name == qualifier || self.qualifiers.contains(qualifier)
+    }
+
+    /// Registers the bean under an additional type, e.g. `TypeId::of::<dyn Trait>()`.
+    pub fn with_provided_type(mut self, type_id: TypeId, caster: BeanCaster) -> Self {
+        self.provides.push(ProvidedType { type_id, caster });
+        self
+    }
+
+    /// Returns the caster for `type_id`, or `None` if the bean is not registered under it.
+    pub fn caster_for(&self, type_id: TypeId) -> Option<BeanCaster> {
+        self.provides
+            .iter()
+            .find(|provided| provided.type_id == type_id)
+            .map(|provided| provided.caster)
+    }
 }
@@ -1,4 +1,5 @@
-use std::any::TypeId;
+use std::error::Error as StdError;
+use summer_core::{ConstructorError, Suggestions, TypeInfo};
 use thiserror::Error;
 
 /// Errors that can occur within the Summer IOC container.
@@ -7,34 +8,130 @@ pub enum IocError {
     #[error("Container has not been initialized yet.")]
     ContainerNotInitialized,
 
+    #[error("Container is frozen; bean definitions can no longer be registered.")]
+    ContainerFrozen,
+
     #[error("Bean with name '{0}' already exists.")]
     BeanAlreadyExists(String),
 
-    #[error("Bean definition not found for name: {0}")]
-    BeanNotFoundByName(String),
+    #[error("Bean definition not found for name: {name}{suggestions}")]
+    BeanNotFoundByName {
+        name: String,
+        suggestions: Suggestions,
+    },
+
+    /// `suggestions` lists beans of similarly named types, e.g. the same struct from another
+    /// module or a trait it implements.
+    #[error("Bean definition not found for type {bean_type}{suggestions}")]
+    BeanNotFoundByType {
+        bean_type: TypeInfo,
+        suggestions: Suggestions,
+    },
+
+    #[error("Multiple beans found for type {0}. Use qualifiers or @Primary to disambiguate.")]
+    MultipleBeansFound(TypeInfo),
+
+    #[error("No bean of type {bean_type} matches qualifier '{qualifier}'{suggestions}")]
+    BeanNotFoundByQualifier {
+        bean_type: TypeInfo,
+        qualifier: String,
+        suggestions: Suggestions,
+    },
 
-    #[error("Bean definition not found for type ID: {0:?}")]
-    BeanNotFoundByType(TypeId),
+    #[error("Dependency cycle detected while creating bean '{0}': {}", .1.join(" -> "))]
+    DependencyCycle(String, Vec<String>), // Ordered cycle path, starting and ending with the same bean
 
+    /// `path` is the chain of beans whose creation failed, from this bean down to the one
+    /// whose constructor raised the root cause, e.g. `["A", "B", "C"]`.
     #[error(
-        "Multiple beans found for type ID: {0:?}. Use qualifiers or @Primary to disambiguate."
+        "Failed to instantiate bean '{bean_name}' (resolution path: {}): {}",
+        .path.join(" -> "),
+        root_cause(.source)
     )]
-    MultipleBeansFound(TypeId),
+    InstantiationError {
+        bean_name: String,
+        path: Vec<String>,
+        #[source]
+        source: ConstructorError,
+    },
 
-    #[error("Dependency cycle detected while creating bean '{0}'. Path: {1:?}")]
-    DependencyCycle(String, Vec<String>), // Store the detected cycle path
+    #[error("Type mismatch for bean '{bean_name}': Requested {requested}, but found {stored}")]
+    TypeMismatchError {
+        bean_name: String,
+        requested: TypeInfo,
+        stored: TypeInfo,
+    },
 
-    #[error("Failed to instantiate bean '{bean_name}': {reason}")]
-    InstantiationError { bean_name: String, reason: String },
+    /// Raised by synchronous lookups, which cannot await the constructor.
+    #[error(
+        "Bean '{0}' has an async constructor; create it with initialize_async or get_bean_async"
+    )]
+    AsyncConstructorRequired(String),
 
-    #[error("Type mismatch for bean '{bean_name}': Requested {requested:?}, but found {stored:?}")]
-    TypeMismatchError {
+    #[error("Scope '{scope}' required by bean '{bean_name}' has not been registered")]
+    ScopeNotRegistered { bean_name: String, scope: String },
+
+    #[error("Lifecycle callback {callback} failed for bean '{bean_name}': {}", root_cause(.source))]
+    LifecycleCallbackError {
+        bean_name: String,
+        callback: &'static str,
+        #[source]
+        source: ConstructorError,
+    },
+
+    #[error("Bean post-processor failed in {phase} for bean '{bean_name}': {}", root_cause(.source))]
+    PostProcessorError {
         bean_name: String,
-        requested: TypeId,
-        stored: TypeId,
+        phase: &'static str,
+        #[source]
+        source: ConstructorError,
     },
 
+    #[error("Event listener {listener} failed: {}", root_cause(.source))]
+    EventListenerError {
+        listener: String,
+        #[source]
+        source: ConstructorError,
+    },
+
+    #[error("{}", describe_startup_failures(.0))]
+    StartupFailed(Vec<(String, IocError)>), // Every bean that failed eager instantiation
+
+    #[error("Lookup in the parent container failed")]
+    ParentLookupError(#[source] ConstructorError),
+
     // Add the missing variant
     #[error("Internal container error: {0}")]
     InternalError(String),
 }
+
+impl IocError {
+    /// The chain of beans whose creation failed, outermost first. Empty for errors that are
+    /// not about creating a bean.
+    pub fn resolution_path(&self) -> &[String] {
+        match self {
+            IocError::InstantiationError { path, .. } => path,
+            _ => &[],
+        }
+    }
+}
+
+/// The innermost error in `error`'s source chain.
+pub(crate) fn root_cause<'a>(error: &'a (dyn StdError + 'static)) -> &'a (dyn StdError + 'static) {
+    let mut current = error;
+    while let Some(source) = current.source() {
+        current = source;
+    }
+    current
+}
+
+fn describe_startup_failures(failures: &[(String, IocError)]) -> String {
+    let mut report = format!(
+        "Container startup failed: {} bean(s) could not be created:",
+        failures.len()
+    );
+    for (bean_name, error) in failures {
+        report.push_str(&format!("\n  - '{}': {}", bean_name, error));
+    }
+    report
+}
@@ -0,0 +1,163 @@
+use crate::error::IocError;
+use parking_lot::RwLock;
+use std::any::TypeId;
+use std::sync::Arc;
+use summer_core::{
+    ApplicationEvent, ApplicationListener, BeanInstance, ConstructorError, EventFuture,
+    EventInstance, EventListenerCallback, EventListenerMetadata,
+};
+
+/// Published by the container at the end of `initialize`, once the eager singletons exist.
+#[derive(Clone, Debug)]
+pub struct ContextRefreshed;
+
+impl ApplicationEvent for ContextRefreshed {}
+
+/// Published by the container when `close` starts, before any bean is destroyed.
+#[derive(Clone, Debug)]
+pub struct ContextClosing;
+
+impl ApplicationEvent for ContextClosing {}
+
+/// Published right after a bean has been created and initialized, before it is handed out.
+#[derive(Clone, Debug)]
+pub struct BeanCreated {
+    pub bean_name: String,
+}
+
+impl ApplicationEvent for BeanCreated {}
+
+type SyncListenerFn = Arc<dyn Fn(&EventInstance) -> Result<(), ConstructorError> + Send + Sync>;
+type AsyncListenerFn = Arc<dyn Fn(EventInstance) -> EventFuture + Send + Sync>;
+
+#[derive(Clone)]
+enum ListenerFn {
+    Sync(SyncListenerFn),
+    Async(AsyncListenerFn),
+}
+
+#[derive(Clone)]
+struct RegisteredListener {
+    event_type: TypeId,
+    /// `Bean::method` for bean listeners, the listener's type otherwise; used in errors.
+    name: String,
+    /// Set for `#[event_listener]` methods, which are dropped when the container closes.
+    bean_name: Option<String>,
+    invoke: ListenerFn,
+}
+
+impl RegisteredListener {
+    fn error(&self, source: ConstructorError) -> IocError {
+        log::error!("Event listener {} failed: {}", self.name, source);
+        IocError::EventListenerError {
+            listener: self.name.clone(),
+            source,
+        }
+    }
+}
+
+/// Delivers published events to the listeners registered for their exact type, in
+/// registration order (like Spring's `ApplicationEventMulticaster`). Each container owns one;
+/// singleton beans with `#[event_listener]` methods are registered when they are created.
+#[derive(Default)]
+pub struct ApplicationEventMulticaster {
+    listeners: RwLock<Vec<RegisteredListener>>,
+}
+
+impl ApplicationEventMulticaster {
+    pub fn add_listener<E: ApplicationEvent>(&self, listener: Arc<dyn ApplicationListener<E>>) {
+        let invoke = ListenerFn::Sync(Arc::new(move |event: &EventInstance| {
+            match event.downcast_ref::<E>() {
+                Some(event) => listener.on_event(event),
+                None => Ok(()),
+            }
+        }));
+        self.listeners.write().push(RegisteredListener {
+            event_type: TypeId::of::<E>(),
+            name: format!("ApplicationListener<{}>", std::any::type_name::<E>()),
+            bean_name: None,
+            invoke,
+        });
+    }
+
+    /// Registers the `#[event_listener]` method described by `metadata` on `bean`.
+    pub(crate) fn add_bean_listener(
+        &self,
+        bean_name: &str,
+        bean: BeanInstance,
+        metadata: &EventListenerMetadata,
+    ) {
+        let invoke = match metadata.callback {
+            EventListenerCallback::Sync(callback) => {
+                ListenerFn::Sync(Arc::new(move |event: &EventInstance| {
+                    callback(&bean, event)
+                }))
+            }
+            EventListenerCallback::Async(callback) => {
+                ListenerFn::Async(Arc::new(move |event| callback(bean.clone(), event)))
+            }
+        };
+        log::debug!(
+            "Registering event listener {}::{} for {}",
+            bean_name,
+            metadata.method_name,
+            (metadata.event_type_name)()
+        );
+        self.listeners.write().push(RegisteredListener {
+            event_type: (metadata.event_type_id)(),
+            name: format!("{}::{}", bean_name, metadata.method_name),
+            bean_name: Some(bean_name.to_string()),
+            invoke,
+        });
+    }
+
+    /// Drops the listeners of beans, whose instances are released when the container closes.
+    pub(crate) fn remove_bean_listeners(&self) {
+        self.listeners
+            .write()
+            .retain(|listener| listener.bean_name.is_none());
+    }
+
+    /// Delivers `event` to every synchronous listener of its type. Async listeners are skipped
+    /// with a warning, as only [`ApplicationEventMulticaster::multicast_event_async`] can await
+    /// them. Stops at the first listener that fails and returns its error.
+    pub fn multicast_event(&self, event: EventInstance) -> Result<(), IocError> {
+        for listener in self.listeners_for(&event) {
+            match &listener.invoke {
+                ListenerFn::Sync(invoke) => {
+                    invoke(&event).map_err(|source| listener.error(source))?
+                }
+                ListenerFn::Async(_) => log::warn!(
+                    "Skipping async event listener {}: the event was published synchronously.",
+                    listener.name
+                ),
+            }
+        }
+        Ok(())
+    }
+
+    /// Async counterpart of [`ApplicationEventMulticaster::multicast_event`], awaiting async
+    /// listeners one after the other.
+    pub async fn multicast_event_async(&self, event: EventInstance) -> Result<(), IocError> {
+        for listener in self.listeners_for(&event) {
+            let result = match &listener.invoke {
+                ListenerFn::Sync(invoke) => invoke(&event),
+                ListenerFn::Async(invoke) => invoke(event.clone()).await,
+            };
+            result.map_err(|source| listener.error(source))?;
+        }
+        Ok(())
+    }
+
+    /// Snapshot of the listeners for the event's type, so listeners may register others or
+    /// publish events themselves.
+    fn listeners_for(&self, event: &EventInstance) -> Vec<RegisteredListener> {
+        let event_type = (**event).type_id();
+        self.listeners
+            .read()
+            .iter()
+            .filter(|listener| listener.event_type == event_type)
+            .cloned()
+            .collect()
+    }
+}
@@ -0,0 +1,15 @@
+//! The immutable lookup snapshot of a frozen container (see `IocContainer::freeze`).
+
+use std::any::TypeId;
+use std::collections::HashMap;
+use summer_core::BeanInstance;
+
+/// The singletons that existed when the snapshot was taken, ready to hand out. Never mutated:
+/// the container swaps in an extended copy when a singleton is created after freezing.
+#[derive(Clone, Default)]
+pub(crate) struct FrozenBeans {
+    /// The instance an unqualified lookup of the type resolves to: the bean itself for its
+    /// concrete type, or its view for a provided type.
+    pub(crate) by_type: HashMap<TypeId, BeanInstance>,
+    pub(crate) by_name: HashMap<String, BeanInstance>,
+}
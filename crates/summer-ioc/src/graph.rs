@@ -0,0 +1,138 @@
+//! Static dependency graph of the container's bean definitions, built from the
+//! dependencies each definition declares (no bean is instantiated).
+
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+/// A dependency of `from` on `to`, as declared by `from`'s constructor or autowired fields.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct DependencyEdge {
+    pub from: String,
+    pub to: String,
+    pub optional: bool,
+    /// Resolved after `from` is constructed, so it cannot take part in a creation cycle.
+    pub lazy: bool,
+}
+
+/// A declared dependency that no registered bean satisfies.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UnresolvedDependency {
+    pub bean_name: String,
+    pub type_name: String,
+    pub qualifier: Option<String>,
+    pub optional: bool,
+    pub reason: String,
+}
+
+/// Bean-to-bean dependency graph returned by `IocContainer::dependency_graph`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct DependencyGraph {
+    /// Bean names, sorted.
+    pub nodes: Vec<String>,
+    /// Edges sorted by `(from, to)`.
+    pub edges: Vec<DependencyEdge>,
+    pub unresolved: Vec<UnresolvedDependency>,
+}
+
+impl DependencyGraph {
+    /// Names of the beans `bean_name` depends on.
+    pub fn dependencies_of<'a>(&'a self, bean_name: &'a str) -> impl Iterator<Item = &'a str> {
+        self.edges
+            .iter()
+            .filter(move |edge| edge.from == bean_name)
+            .map(|edge| edge.to.as_str())
+    }
+
+    /// Finds a dependency cycle, returned as the ordered path that closes it,
+    /// e.g. `["A", "B", "C", "A"]`. Lazy edges are ignored, since they are legitimate ways to
+    /// express circular references. The search order is deterministic.
+    pub fn find_cycle(&self) -> Option<Vec<String>> {
+        let mut adjacency: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for node in &self.nodes {
+            adjacency.entry(node).or_default();
+        }
+        for edge in self.edges.iter().filter(|edge| !edge.lazy) {
+            adjacency.entry(&edge.from).or_default().push(&edge.to);
+        }
+
+        #[derive(Clone, Copy, PartialEq)]
+        enum Visit {
+            InProgress,
+            Done,
+        }
+        let mut visits: HashMap<&str, Visit> = HashMap::new();
+        let mut path: Vec<&str> = Vec::new();
+
+        fn visit<'a>(
+            node: &'a str,
+            adjacency: &BTreeMap<&'a str, Vec<&'a str>>,
+            visits: &mut HashMap<&'a str, Visit>,
+            path: &mut Vec<&'a str>,
+        ) -> Option<Vec<String>> {
+            match visits.get(node) {
+                Some(Visit::Done) => return None,
+                Some(Visit::InProgress) => {
+                    let start = path.iter().position(|n| *n == node)?;
+                    let mut cycle: Vec<String> =
+                        path[start..].iter().map(|n| n.to_string()).collect();
+                    cycle.push(node.to_string());
+                    return Some(cycle);
+                }
+                None => {}
+            }
+            visits.insert(node, Visit::InProgress);
+            path.push(node);
+            for next in adjacency.get(node).into_iter().flatten() {
+                if let Some(cycle) = visit(next, adjacency, visits, path) {
+                    return Some(cycle);
+                }
+            }
+            path.pop();
+            visits.insert(node, Visit::Done);
+            None
+        }
+
+        adjacency
+            .keys()
+            .find_map(|node| visit(node, &adjacency, &mut visits, &mut path))
+    }
+
+    /// Renders the graph in Graphviz DOT format. Optional dependencies are dashed, lazy ones
+    /// dotted, and unresolved ones point at a red placeholder node named after the missing type.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph beans {\n");
+        for node in &self.nodes {
+            let _ = writeln!(dot, "    {:?};", node);
+        }
+        for edge in &self.edges {
+            let style = match (edge.lazy, edge.optional) {
+                (true, _) => " [style=dotted]",
+                (false, true) => " [style=dashed]",
+                (false, false) => "",
+            };
+            let _ = writeln!(dot, "    {:?} -> {:?}{};", edge.from, edge.to, style);
+        }
+        for missing in &self.unresolved {
+            let _ = writeln!(
+                dot,
+                "    {:?} [shape=box, color=red];\n    {:?} -> {:?} [color=red{}];",
+                missing.type_name,
+                missing.bean_name,
+                missing.type_name,
+                if missing.optional {
+                    ", style=dashed"
+                } else {
+                    ""
+                }
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders the graph as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("dependency graph is always serializable")
+    }
+}
@@ -0,0 +1,50 @@
+//! Runtime view of the container's beans, for debugging and admin endpoints.
+
+use crate::condition::SkippedBean;
+use crate::scope::BeanScope;
+use serde::{Serialize, Serializer};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Snapshot of one registered bean, as returned by `IocContainer::bean_descriptors`.
+#[derive(Clone, Debug, Serialize)]
+pub struct BeanDescriptor {
+    pub name: String,
+    /// The Rust type path of the bean, from `std::any::type_name`.
+    pub type_name: &'static str,
+    pub scope: BeanScope,
+    /// Whether the container holds an instance; always `false` for non-singleton scopes.
+    pub instantiated: bool,
+    /// When the singleton instance was created, serialized as milliseconds since the epoch.
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub created_at: Option<SystemTime>,
+    /// Names of the beans this bean depends on, resolved like injection would resolve them.
+    pub dependencies: Vec<String>,
+}
+
+/// Every bean of the container at one point in time, returned by `IocContainer::report`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ContainerReport {
+    pub initialized: bool,
+    /// Sorted by bean name.
+    pub beans: Vec<BeanDescriptor>,
+    /// Beans left out by a failed registration condition.
+    pub skipped_beans: Vec<SkippedBean>,
+}
+
+impl ContainerReport {
+    /// Renders the report as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("container report is always serializable")
+    }
+}
+
+fn serialize_timestamp<S: Serializer>(
+    time: &Option<SystemTime>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let millis = time.map(|time| {
+        time.duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() as u64)
+    });
+    millis.serialize(serializer)
+}
@@ -1,17 +1,30 @@
 //! Summer IOC (Inversion of Control) Core Crate
 //! Provides the foundation for dependency injection and component management.
 
+mod command;
+pub mod condition;
 pub mod container;
 pub mod definition;
 pub mod error;
+pub mod event;
+mod frozen;
+pub mod graph;
+pub mod introspection;
+pub mod processor;
+pub mod scope;
+mod suggest;
 
 // Re-export key types for easier access
+pub use condition::{BeanCondition, SkippedBean};
 pub use container::IocContainer;
-pub use definition::BeanDefinition;
+pub use definition::{BeanDefinition, BeanDependency, BeanOverridePolicy, ProvidedType};
 pub use error::IocError;
+pub use event::{ApplicationEventMulticaster, BeanCreated, ContextClosing, ContextRefreshed};
+pub use graph::{DependencyEdge, DependencyGraph, UnresolvedDependency};
+pub use introspection::{BeanDescriptor, ContainerReport};
+pub use processor::BeanPostProcessor;
+pub use scope::{BeanScope, Scope};
 
 // --- Traits and other public items to be added later ---
 // pub trait BeanFactory { ... }
 // pub trait ApplicationContext: BeanFactory { ... }
-// pub trait BeanPostProcessor { ... }
-// pub trait Aware { ... } // e.g., BeanNameAware, ApplicationContextAware
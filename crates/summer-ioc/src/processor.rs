@@ -0,0 +1,35 @@
+use summer_core::{BeanInstance, ConstructorError};
+
+/// Hooks into the creation of every bean in a container, e.g. to record metrics, validate
+/// beans or reconfigure them. Register processors with
+/// `IocContainer::register_bean_post_processor` before the container is initialized.
+///
+/// Each freshly constructed bean is passed through `before_init` of every processor (in
+/// registration order), then its `#[post_construct]` callback runs, then it is passed through
+/// `after_init`. The instance a processor returns is the one handed to the next processor and
+/// finally cached and injected, so a processor may replace the bean with another instance.
+///
+/// Only same-type replacement is supported: the returned instance must be of the bean's
+/// concrete type, otherwise creation fails with `IocError::TypeMismatchError`. Wrapping a bean
+/// in a proxy of another type, even one implementing the same provided traits, is not possible.
+pub trait BeanPostProcessor: Send + Sync {
+    /// Called after the bean is constructed, before its `#[post_construct]` callback.
+    fn before_init(
+        &self,
+        bean_name: &str,
+        bean: BeanInstance,
+    ) -> Result<BeanInstance, ConstructorError> {
+        let _ = bean_name;
+        Ok(bean)
+    }
+
+    /// Called after the bean's `#[post_construct]` callback has completed.
+    fn after_init(
+        &self,
+        bean_name: &str,
+        bean: BeanInstance,
+    ) -> Result<BeanInstance, ConstructorError> {
+        let _ = bean_name;
+        Ok(bean)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::error::IocError;
+use serde::{Serialize, Serializer};
+use std::fmt;
+use summer_core::BeanInstance;
+
+/// The scope of a bean: how long an instance lives and when a new one is created.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BeanScope {
+    /// One shared instance for the lifetime of the container (the default).
+    #[default]
+    Singleton,
+    /// A new instance for every lookup or injection.
+    Prototype,
+    /// Delegated to the [`Scope`] registered under this name via `IocContainer::register_scope`.
+    Custom(String),
+}
+
+impl BeanScope {
+    pub const SINGLETON: &'static str = "singleton";
+    pub const PROTOTYPE: &'static str = "prototype";
+
+    /// Parses a scope name as written in `#[component(scope = "...")]`.
+    /// Names other than the built-in ones refer to custom scopes.
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            Self::SINGLETON => BeanScope::Singleton,
+            Self::PROTOTYPE => BeanScope::Prototype,
+            custom => BeanScope::Custom(custom.to_string()),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            BeanScope::Singleton => Self::SINGLETON,
+            BeanScope::Prototype => Self::PROTOTYPE,
+            BeanScope::Custom(name) => name,
+        }
+    }
+}
+
+/// Serialized as its name, e.g. `"singleton"`.
+impl Serialize for BeanScope {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl fmt::Display for BeanScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Strategy for managing instances of beans in a custom scope (like Spring's `Scope`).
+///
+/// The container calls [`Scope::get`] on every lookup of a bean in this scope; the scope
+/// decides whether to return a cached instance (e.g. one per tenant or per thread) or to
+/// create a new one through `factory`, which runs the bean's constructor.
+pub trait Scope: Send + Sync {
+    fn get(
+        &self,
+        bean_name: &str,
+        factory: &dyn Fn() -> Result<BeanInstance, IocError>,
+    ) -> Result<BeanInstance, IocError>;
+}
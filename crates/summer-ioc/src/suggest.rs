@@ -0,0 +1,58 @@
+//! "Did you mean" hints for lookups that found no bean.
+
+/// At most this many suggestions are attached to an error.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// The candidates that look like a typo of `wanted` (ignoring case), closest first; only the
+/// exact matches if some candidate differs from `wanted` in case alone.
+pub(crate) fn similar<'a>(
+    wanted: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Vec<&'a str> {
+    let wanted = wanted.to_lowercase();
+    let mut matches: Vec<(usize, &str)> = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let lowered = candidate.to_lowercase();
+            let distance = edit_distance(&wanted, &lowered);
+            let close = distance <= (wanted.chars().count() / 3).max(1)
+                || lowered.contains(&wanted)
+                || wanted.contains(&lowered);
+            close.then_some((distance, candidate))
+        })
+        .collect();
+    matches.sort();
+    matches.dedup();
+    // A difference in case only is almost certainly the one that was meant.
+    if matches.first().is_some_and(|(distance, _)| *distance == 0) {
+        matches.retain(|(distance, _)| *distance == 0);
+    }
+    matches.truncate(MAX_SUGGESTIONS);
+    matches
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+/// The last path segment of a type name without generics or `dyn`, e.g. `Repository` for
+/// `dyn app::Repository` and `Cache` for `app::Cache<u32>`.
+pub(crate) fn short_type_name(type_name: &str) -> &str {
+    let type_name = type_name.strip_prefix("dyn ").unwrap_or(type_name);
+    let path = type_name.split('<').next().unwrap_or(type_name);
+    path.rsplit("::").next().unwrap_or(path).trim()
+}
+
+/// Levenshtein distance between `a` and `b`, in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
@@ -0,0 +1,273 @@
+//! Async constructors and concurrent startup.
+
+use std::any::TypeId;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use summer_core::{resolve_bean, BeanProviderRef, ConstructorError};
+use summer_ioc::{BeanDefinition, BeanDependency, BeanScope, IocContainer, IocError};
+use summer_macros::{component, constructor};
+
+#[component]
+#[derive(Default)]
+struct GreetingRepository;
+
+impl GreetingRepository {
+    fn greeting(&self) -> &'static str {
+        "hello"
+    }
+}
+
+/// Stands in for a client that has to connect before it can be used.
+#[component]
+struct MessageBrokerClient {
+    greetings: Arc<GreetingRepository>,
+    connected: bool,
+}
+
+#[constructor]
+impl MessageBrokerClient {
+    async fn new(greetings: Arc<GreetingRepository>) -> Self {
+        let connected = std::future::ready(true).await;
+        MessageBrokerClient {
+            greetings,
+            connected,
+        }
+    }
+}
+
+#[component]
+struct NotificationService {
+    #[autowired]
+    broker: Arc<MessageBrokerClient>,
+}
+
+#[tokio::test]
+async fn test_async_constructors() {
+    struct SlowClient {
+        endpoint: String,
+    }
+
+    let container = IocContainer::new();
+    // Awaits a tokio timer, which would never fire if the container blocked on it here.
+    container
+        .register_bean_definition(BeanDefinition::for_async_factory(
+            "slowClient",
+            |_: BeanProviderRef| async {
+                tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+                Ok(SlowClient {
+                    endpoint: "amqp://localhost".to_string(),
+                })
+            },
+        ))
+        .unwrap();
+    container
+        .initialize_async()
+        .await
+        .expect("Failed to initialize container");
+
+    let client = container.get_bean::<SlowClient>().unwrap();
+    assert_eq!(client.endpoint, "amqp://localhost");
+    let notifications = container
+        .get_bean_async::<NotificationService>()
+        .await
+        .unwrap();
+    assert!(notifications.broker.connected);
+    assert_eq!(notifications.broker.greetings.greeting(), "hello");
+    assert!(Arc::ptr_eq(
+        &notifications.broker,
+        &container.get_bean::<MessageBrokerClient>().unwrap()
+    ));
+
+    // Lazily, the async constructor runs on the first async lookup of a dependent bean.
+    let container = IocContainer::new();
+    container.set_eager_init(false);
+    container
+        .initialize_async()
+        .await
+        .expect("Failed to initialize container");
+    let notifications = container
+        .get_bean_async::<NotificationService>()
+        .await
+        .unwrap();
+    assert!(notifications.broker.connected);
+}
+
+#[test]
+fn test_async_constructors_are_rejected_on_the_sync_path() {
+    let container = IocContainer::new();
+    match container.initialize() {
+        Err(IocError::StartupFailed(failures)) => {
+            let names: Vec<&str> = failures.iter().map(|(name, _)| name.as_str()).collect();
+            assert_eq!(names, ["MessageBrokerClient", "NotificationService"]);
+        }
+        other => panic!("expected a startup failure, got {:?}", other),
+    }
+
+    let container = IocContainer::new();
+    container.set_eager_init(false);
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+    assert!(matches!(
+        container.get_bean::<MessageBrokerClient>(),
+        Err(IocError::AsyncConstructorRequired(name)) if name == "MessageBrokerClient"
+    ));
+    // Dependents report it as their root cause.
+    let err = container.get_bean::<NotificationService>().err().unwrap();
+    assert_eq!(err.resolution_path(), ["NotificationService"]);
+    assert!(err.to_string().contains("get_bean_async"), "{}", err);
+}
+
+#[tokio::test]
+async fn test_sync_lookup_inside_a_runtime_does_not_block() {
+    struct Ticket(u32);
+
+    let container = IocContainer::new();
+    container.set_eager_init(false);
+    let issued = Arc::new(std::sync::atomic::AtomicU32::new(0));
+    let counter = issued.clone();
+    container
+        .register_bean_definition(
+            BeanDefinition::for_async_factory("ticket", move |_: BeanProviderRef| {
+                let number = counter.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+                    Ok(Ticket(number))
+                }
+            })
+            .with_scope(BeanScope::Prototype),
+        )
+        .unwrap();
+    container
+        .initialize_async()
+        .await
+        .expect("Failed to initialize container");
+
+    // Blocking on the timer here would deadlock this single-threaded runtime.
+    assert!(matches!(
+        container.get_bean::<Ticket>(),
+        Err(IocError::AsyncConstructorRequired(_))
+    ));
+    let first = container.get_bean_async::<Ticket>().await.unwrap();
+    let second = container.get_bean_async::<Ticket>().await.unwrap();
+    assert_eq!((first.0, second.0), (0, 1));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_parallel_startup() {
+    use std::sync::atomic::AtomicUsize;
+
+    struct Connection;
+    struct Aggregator {
+        connections_before: usize,
+    }
+
+    let running = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    let created = Arc::new(AtomicUsize::new(0));
+
+    let container = IocContainer::new();
+    container.set_startup_parallelism(3);
+    let counted = created.clone();
+    let mut aggregator = BeanDefinition::for_factory("aggregator", move |_| {
+        Ok(Aggregator {
+            connections_before: counted.load(Ordering::SeqCst),
+        })
+    });
+    for i in 0..6 {
+        let name = format!("connection{}", i);
+        let (running, peak, created) = (running.clone(), peak.clone(), created.clone());
+        container
+            .register_factory(name.clone(), move |_: &BeanProviderRef| {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                std::thread::sleep(std::time::Duration::from_millis(20));
+                running.fetch_sub(1, Ordering::SeqCst);
+                created.fetch_add(1, Ordering::SeqCst);
+                Ok(Connection)
+            })
+            .unwrap();
+        aggregator = aggregator.with_dependency(BeanDependency {
+            type_id: TypeId::of::<Connection>(),
+            type_name: "Connection".to_string(),
+            qualifier: Some(name),
+            optional: false,
+            collection: false,
+            lazy: false,
+        });
+    }
+    container.register_bean_definition(aggregator).unwrap();
+    container
+        .initialize_async()
+        .await
+        .expect("Failed to initialize container");
+
+    // Dependencies are created before their dependents, independent beans side by side.
+    let aggregator = container.get_bean::<Aggregator>().unwrap();
+    assert_eq!(aggregator.connections_before, 6);
+    let peak = peak.load(Ordering::SeqCst);
+    assert!(peak > 1 && peak <= 3, "peak concurrency was {}", peak);
+
+    let container = IocContainer::new();
+    container.set_startup_parallelism(4);
+    for name in ["broken2", "broken1"] {
+        container
+            .register_factory(name, move |_: &BeanProviderRef| {
+                Err::<Connection, _>(ConstructorError::BaseMsgError(format!("{} failed", name)))
+            })
+            .unwrap();
+    }
+    match container.initialize_async().await {
+        Err(IocError::StartupFailed(failures)) => {
+            let names: Vec<&str> = failures.iter().map(|(name, _)| name.as_str()).collect();
+            assert_eq!(names, ["broken1", "broken2"]);
+        }
+        other => panic!("expected a startup failure, got {:?}", other),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_parallel_startup_reports_cycles_like_sequential_startup() {
+    struct A;
+    struct B;
+
+    // Undeclared dependencies: both factories are started at once on the blocking pool.
+    fn register_mutual_factories(container: &IocContainer) {
+        container
+            .register_factory("a", |provider: &BeanProviderRef| {
+                std::thread::sleep(std::time::Duration::from_millis(20));
+                resolve_bean::<B>(&**provider)?;
+                Ok(A)
+            })
+            .unwrap();
+        container
+            .register_factory("b", |provider: &BeanProviderRef| {
+                std::thread::sleep(std::time::Duration::from_millis(20));
+                resolve_bean::<A>(&**provider)?;
+                Ok(B)
+            })
+            .unwrap();
+    }
+
+    fn describe(result: Result<(), IocError>) -> Vec<(String, String)> {
+        match result {
+            Err(IocError::StartupFailed(failures)) => failures
+                .into_iter()
+                .map(|(name, e)| (name, e.to_string()))
+                .collect(),
+            other => panic!("expected a startup failure, got {:?}", other),
+        }
+    }
+
+    // With a parallelism of 1 the singletons are created one after the other.
+    let sequential = IocContainer::new();
+    register_mutual_factories(&sequential);
+    let expected = describe(sequential.initialize_async().await);
+    assert_eq!(expected.len(), 2);
+    assert!(expected[0].1.contains("a -> b -> a"), "{}", expected[0].1);
+
+    let container = IocContainer::new();
+    container.set_startup_parallelism(4);
+    register_mutual_factories(&container);
+    assert_eq!(describe(container.initialize_async().await), expected);
+}
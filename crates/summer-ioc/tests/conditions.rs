@@ -0,0 +1,126 @@
+//! Property, profile, feature and missing-bean conditions.
+
+// `metrics` is deliberately not a feature of this crate.
+#![allow(unexpected_cfgs)]
+
+use summer_ioc::{BeanCondition, BeanDefinition, IocContainer};
+use summer_macros::component;
+
+trait Cache: Send + Sync {
+    fn backend(&self) -> &'static str;
+}
+
+#[component(provides = dyn Cache)]
+#[conditional_on_property(name = "cache.type", having_value = "redis")]
+#[derive(Default)]
+struct RedisCache;
+
+impl Cache for RedisCache {
+    fn backend(&self) -> &'static str {
+        "redis"
+    }
+}
+
+#[component(provides = dyn Cache)]
+#[conditional_on_missing_bean(dyn Cache)]
+#[derive(Default)]
+struct InMemoryCache;
+
+impl Cache for InMemoryCache {
+    fn backend(&self) -> &'static str {
+        "memory"
+    }
+}
+
+#[component]
+#[profile("dev")]
+#[derive(Default)]
+struct DevDataSeeder;
+
+#[component]
+#[conditional_on_feature("metrics")]
+#[derive(Default)]
+struct MetricsExporter;
+
+#[test]
+fn test_conditional_beans() {
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    assert_eq!(
+        container.get_bean_dyn::<dyn Cache>().unwrap().backend(),
+        "memory"
+    );
+    assert!(container.get_bean::<DevDataSeeder>().is_err());
+    assert!(container.get_bean::<MetricsExporter>().is_err());
+    let skipped = container.skipped_beans();
+    let reasons: Vec<(&str, &str)> = skipped
+        .iter()
+        .map(|s| (s.bean_name.as_str(), s.reason.as_str()))
+        .collect();
+    assert_eq!(
+        reasons,
+        [
+            ("DevDataSeeder", "no matching profile in [dev]"),
+            ("MetricsExporter", "cargo feature 'metrics' is not enabled"),
+            ("RedisCache", "property 'cache.type' is not set"),
+        ]
+    );
+
+    let container = IocContainer::new();
+    container.set_property("cache.type", "redis");
+    container.set_active_profiles(["dev"]);
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    assert_eq!(
+        container.get_bean_dyn::<dyn Cache>().unwrap().backend(),
+        "redis"
+    );
+    assert!(container.get_bean::<DevDataSeeder>().is_ok());
+    let skipped = container.skipped_beans();
+    assert_eq!(skipped.len(), 2);
+    assert_eq!(skipped[0].bean_name, "InMemoryCache");
+    assert!(skipped[0].reason.starts_with("bean 'RedisCache' of type"));
+    assert_eq!(skipped[1].bean_name, "MetricsExporter");
+}
+
+#[test]
+fn test_skipped_definitions_are_reconsidered_after_close() {
+    struct Tracer;
+
+    let container = IocContainer::new();
+    container
+        .register_bean_definition(
+            BeanDefinition::for_factory("tracer", |_| Ok(Tracer)).with_condition(
+                BeanCondition::OnProperty {
+                    name: "tracing.enabled".to_string(),
+                    having_value: None,
+                    match_if_missing: false,
+                },
+            ),
+        )
+        .unwrap();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+    assert!(container.get_bean::<Tracer>().is_err());
+    assert!(container
+        .skipped_beans()
+        .iter()
+        .any(|s| s.bean_name == "tracer"));
+
+    container.close().unwrap();
+    container.set_property("tracing.enabled", "true");
+    container
+        .initialize()
+        .expect("Failed to reinitialize container");
+    assert!(container.get_bean::<Tracer>().is_ok());
+    assert!(container
+        .skipped_beans()
+        .iter()
+        .all(|s| s.bean_name != "tracer"));
+}
@@ -0,0 +1,167 @@
+//! The application event bus, listener methods and lifecycle events.
+
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use summer_core::{ApplicationEvent, ApplicationListener, BeanProviderRef, ConstructorError};
+use summer_ioc::{
+    BeanCreated, BeanDefinition, ContextClosing, ContextRefreshed, IocContainer, IocError,
+};
+use summer_macros::{component, event_listener};
+
+#[component(scope = "prototype")]
+#[derive(Default)]
+struct RequestContext;
+
+struct OrderPlaced {
+    id: u32,
+}
+
+impl ApplicationEvent for OrderPlaced {}
+
+struct OrderRejected;
+
+impl ApplicationEvent for OrderRejected {}
+
+#[component]
+#[derive(Default)]
+struct AuditTrail {
+    entries: parking_lot::Mutex<Vec<String>>,
+}
+
+#[event_listener]
+impl AuditTrail {
+    #[event_listener]
+    fn on_refreshed(&self, _event: &ContextRefreshed) {
+        self.entries.lock().push("refreshed".to_string());
+    }
+
+    #[event_listener]
+    fn on_bean_created(&self, event: &BeanCreated) {
+        self.entries
+            .lock()
+            .push(format!("created:{}", event.bean_name));
+    }
+
+    #[event_listener]
+    async fn on_bean_created_async(&self, event: &BeanCreated) {
+        self.entries
+            .lock()
+            .push(format!("awaited:{}", event.bean_name));
+    }
+
+    #[event_listener]
+    async fn on_order_placed(&self, event: &OrderPlaced) {
+        self.entries.lock().push(format!("order:{}", event.id));
+    }
+
+    #[event_listener]
+    fn on_rejected(&self, _event: &OrderRejected) -> Result<(), String> {
+        Err("audit trail is read-only".to_string())
+    }
+
+    #[event_listener]
+    fn on_closing(&self, _event: &ContextClosing) {
+        self.entries.lock().push("closing".to_string());
+    }
+
+    fn contains(&self, entry: &str) -> bool {
+        self.entries.lock().iter().any(|e| e == entry)
+    }
+}
+
+#[tokio::test]
+async fn test_application_events() {
+    struct OrderCounter(std::sync::atomic::AtomicUsize);
+    struct Connection;
+
+    impl ApplicationListener<OrderPlaced> for OrderCounter {
+        fn on_event(&self, _event: &OrderPlaced) -> Result<(), ConstructorError> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    let container = IocContainer::new();
+    let counter = Arc::new(OrderCounter(Default::default()));
+    container.add_application_listener::<OrderPlaced>(counter.clone());
+    container
+        .register_bean_definition(
+            BeanDefinition::for_async_factory("connection", |_: BeanProviderRef| async {
+                Ok(Connection)
+            })
+            .with_lazy(true),
+        )
+        .unwrap();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    let audit = container.get_bean::<AuditTrail>().unwrap();
+    assert!(audit.contains("refreshed"));
+    container.get_bean::<RequestContext>().unwrap();
+    assert!(audit.contains("created:RequestContext"));
+    // Beans created asynchronously publish BeanCreated asynchronously.
+    assert!(!audit.contains("awaited:RequestContext"));
+    container.get_bean_async::<Connection>().await.unwrap();
+    assert!(audit.contains("created:connection") && audit.contains("awaited:connection"));
+
+    // Synchronous publishing skips async listeners instead of blocking on them.
+    container.publish_event(OrderPlaced { id: 1 }).unwrap();
+    container
+        .publish_event_async(OrderPlaced { id: 2 })
+        .await
+        .unwrap();
+    assert!(!audit.contains("order:1") && audit.contains("order:2"));
+    assert_eq!(counter.0.load(Ordering::SeqCst), 2);
+
+    match container.publish_event(OrderRejected) {
+        Err(IocError::EventListenerError { listener, .. }) => {
+            assert_eq!(listener, "AuditTrail::on_rejected")
+        }
+        other => panic!("expected an EventListenerError, got {:?}", other.err()),
+    }
+
+    // Bean listeners are dropped with their beans; registered listeners stay.
+    container.close().unwrap();
+    assert!(audit.contains("closing"));
+    container.publish_event(OrderPlaced { id: 3 }).unwrap();
+    assert!(!audit.contains("order:3"));
+    assert_eq!(counter.0.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn test_failing_refresh_listener_fails_startup() {
+    struct RefreshGate(std::sync::atomic::AtomicBool);
+
+    impl ApplicationListener<ContextRefreshed> for RefreshGate {
+        fn on_event(&self, _event: &ContextRefreshed) -> Result<(), ConstructorError> {
+            if self.0.load(Ordering::SeqCst) {
+                Ok(())
+            } else {
+                Err(ConstructorError::BaseMsgError("not ready".to_string()))
+            }
+        }
+    }
+
+    let container = IocContainer::new();
+    let gate = Arc::new(RefreshGate(Default::default()));
+    container.add_application_listener::<ContextRefreshed>(gate.clone());
+    assert!(matches!(
+        container.initialize(),
+        Err(IocError::EventListenerError { .. })
+    ));
+    assert!(!container.report().initialized);
+    assert!(matches!(
+        container.get_bean::<AuditTrail>(),
+        Err(IocError::ContainerNotInitialized)
+    ));
+
+    gate.0.store(true, Ordering::SeqCst);
+    container
+        .initialize()
+        .expect("Failed to reinitialize container");
+    assert!(container
+        .get_bean::<AuditTrail>()
+        .unwrap()
+        .contains("refreshed"));
+}
@@ -0,0 +1,130 @@
+//! Frozen containers serving singleton lookups from a snapshot.
+
+use std::sync::Arc;
+use summer_ioc::{BeanDefinition, IocContainer, IocError};
+use summer_macros::{component, constructor};
+
+#[component]
+#[derive(Default)]
+struct GreetingRepository;
+
+#[component]
+struct GreetingService {
+    _repository: Arc<GreetingRepository>,
+}
+
+#[constructor]
+impl GreetingService {
+    fn new(repository: Arc<GreetingRepository>) -> Self {
+        GreetingService {
+            _repository: repository,
+        }
+    }
+}
+
+trait UserRepository: Send + Sync {}
+
+#[component(provides = dyn UserRepository)]
+#[derive(Default)]
+struct InMemoryUserRepository;
+
+impl UserRepository for InMemoryUserRepository {}
+
+trait Formatter: Send + Sync {}
+
+#[component(provides = dyn Formatter, lazy)]
+#[derive(Default)]
+struct JsonFormatter;
+
+impl Formatter for JsonFormatter {}
+
+#[component(scope = "prototype")]
+#[derive(Default)]
+struct RequestContext;
+
+#[test]
+fn test_frozen_container() {
+    struct Clock;
+
+    let container = IocContainer::new();
+    assert!(matches!(
+        container.freeze(),
+        Err(IocError::ContainerNotInitialized)
+    ));
+    container
+        .register_bean_definition(
+            BeanDefinition::for_factory("clock", |_| Ok(Clock)).with_lazy(true),
+        )
+        .unwrap();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+    let service = container.get_bean::<GreetingService>().unwrap();
+    let repository = container.get_bean_dyn::<dyn UserRepository>().unwrap();
+
+    container.freeze().unwrap();
+    assert!(container.is_frozen());
+    assert!(Arc::ptr_eq(
+        &service,
+        &container.get_bean::<GreetingService>().unwrap()
+    ));
+    assert!(Arc::ptr_eq(
+        &repository,
+        &container.get_bean_dyn::<dyn UserRepository>().unwrap()
+    ));
+    assert!(container
+        .get_bean_by_name::<GreetingService>("GreetingService")
+        .is_ok());
+    // Qualified and prototype lookups still take the regular path.
+    assert!(container
+        .get_qualified_bean::<GreetingService>("GreetingService")
+        .is_ok());
+    assert!(container.get_bean::<RequestContext>().is_ok());
+
+    // A lazy singleton created after freezing is added to the snapshot.
+    let clock = container.get_bean::<Clock>().unwrap();
+    assert!(Arc::ptr_eq(&clock, &container.get_bean::<Clock>().unwrap()));
+    let formatter = container.get_bean_dyn::<dyn Formatter>().unwrap();
+    assert!(Arc::ptr_eq(
+        &formatter,
+        &container.get_bean_dyn::<dyn Formatter>().unwrap()
+    ));
+    assert!(container.get_bean::<JsonFormatter>().is_ok());
+
+    assert!(matches!(
+        container.register_singleton("late", Arc::new(Clock)),
+        Err(IocError::ContainerFrozen)
+    ));
+    container.close().unwrap();
+    assert!(!container.is_frozen());
+}
+
+#[test]
+fn test_replacing_a_bean_of_a_frozen_container_fails() {
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+    container.freeze().unwrap();
+    let original = container.get_bean::<GreetingRepository>().unwrap();
+
+    assert!(matches!(
+        container.replace_bean_with_instance("GreetingRepository", Arc::new(GreetingRepository)),
+        Err(IocError::ContainerFrozen)
+    ));
+    assert!(Arc::ptr_eq(
+        &original,
+        &container.get_bean::<GreetingRepository>().unwrap()
+    ));
+
+    // The failed replacement does not suppress the component once the container restarts.
+    container.close().unwrap();
+    container
+        .initialize()
+        .expect("Failed to reinitialize container");
+    assert!(container.get_bean::<GreetingService>().is_ok());
+    assert!(!Arc::ptr_eq(
+        &original,
+        &container.get_bean::<GreetingRepository>().unwrap()
+    ));
+}
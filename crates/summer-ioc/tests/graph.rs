@@ -0,0 +1,341 @@
+//! The dependency graph, cycle detection, concurrent creation and failure paths.
+
+use std::any::TypeId;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use summer_core::{resolve_bean, BeanInstance, BeanProviderRef, ConstructorError};
+use summer_ioc::{BeanDefinition, BeanDependency, IocContainer, IocError};
+use summer_macros::{component, constructor};
+
+#[component]
+#[derive(Default)]
+struct GreetingRepository;
+
+#[component]
+struct GreetingService {
+    _repository: Arc<GreetingRepository>,
+}
+
+#[constructor]
+impl GreetingService {
+    fn new(repository: Arc<GreetingRepository>) -> Self {
+        GreetingService {
+            _repository: repository,
+        }
+    }
+}
+
+/// Not a component: used to exercise failed dependency resolution.
+struct UnregisteredDependency;
+
+#[component]
+struct GreetingController {
+    #[autowired]
+    _service: Arc<GreetingService>,
+    #[autowired]
+    _audit_log: Option<Arc<UnregisteredDependency>>,
+}
+
+#[component]
+#[derive(Default)]
+struct ShutdownLog;
+
+#[component]
+struct ConnectionPool {
+    #[autowired]
+    _log: Arc<ShutdownLog>,
+}
+
+#[component]
+struct SessionRepository {
+    #[autowired]
+    _pool: Arc<ConnectionPool>,
+    #[autowired]
+    _log: Arc<ShutdownLog>,
+}
+
+trait PaymentGateway: Send + Sync {}
+
+#[component(provides = dyn PaymentGateway, primary)]
+#[derive(Default)]
+struct StandardGateway;
+
+impl PaymentGateway for StandardGateway {}
+
+#[component(provides = dyn PaymentGateway, qualifier = "fast")]
+#[derive(Default)]
+struct FastGateway;
+
+impl PaymentGateway for FastGateway {}
+
+#[component]
+struct RefundService {
+    _gateway: Arc<dyn PaymentGateway>,
+}
+
+#[constructor]
+impl RefundService {
+    fn new(#[qualifier("FastGateway")] gateway: Arc<dyn PaymentGateway>) -> Self {
+        RefundService { _gateway: gateway }
+    }
+}
+
+fn construct_with_missing_dependency(
+    provider: BeanProviderRef,
+) -> Result<BeanInstance, ConstructorError> {
+    let dependency = resolve_bean::<UnregisteredDependency>(&*provider)?;
+    Ok(dependency)
+}
+
+#[test]
+fn test_dependency_graph() {
+    let container = IocContainer::new();
+    container.set_eager_init(false);
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    let graph = container.dependency_graph();
+    let mut repository_deps: Vec<&str> = graph.dependencies_of("SessionRepository").collect();
+    repository_deps.sort();
+    assert_eq!(repository_deps, ["ConnectionPool", "ShutdownLog"]);
+    assert_eq!(
+        graph.dependencies_of("RefundService").collect::<Vec<_>>(),
+        ["FastGateway"]
+    );
+    assert!(graph.unresolved.iter().any(|missing| {
+        missing.bean_name == "GreetingController"
+            && missing.optional
+            && missing.type_name.ends_with("UnregisteredDependency")
+    }));
+    assert!(graph.find_cycle().is_none());
+
+    assert!(graph
+        .to_dot()
+        .contains("\"SessionRepository\" -> \"ConnectionPool\";"));
+    let json: serde_json::Value = serde_json::from_str(&graph.to_json()).unwrap();
+    assert!(json["nodes"]
+        .as_array()
+        .unwrap()
+        .contains(&"GreetingService".into()));
+}
+
+#[test]
+fn test_dependency_cycle_is_detected_before_instantiation() {
+    struct A;
+    struct B;
+    struct C;
+
+    fn dependency_on<T: 'static>() -> BeanDependency {
+        BeanDependency {
+            type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>().to_string(),
+            qualifier: None,
+            optional: false,
+            collection: false,
+            lazy: false,
+        }
+    }
+
+    let container = IocContainer::new();
+    for (name, type_id, dependency) in [
+        ("a", TypeId::of::<A>(), dependency_on::<B>()),
+        ("b", TypeId::of::<B>(), dependency_on::<C>()),
+        ("c", TypeId::of::<C>(), dependency_on::<A>()),
+    ] {
+        container
+            .register_bean_definition(
+                BeanDefinition::new(name.to_string(), type_id, construct_with_missing_dependency)
+                    .with_dependency(dependency),
+            )
+            .unwrap();
+    }
+
+    match container.initialize() {
+        Err(IocError::DependencyCycle(_, path)) => assert_eq!(path, ["a", "b", "c", "a"]),
+        other => panic!("expected a dependency cycle, got {:?}", other),
+    }
+
+    // Once the cycle is broken, initialization can be retried.
+    container
+        .replace_bean_definition(BeanDefinition::for_factory("c", |_| Ok(C)))
+        .unwrap();
+    container.set_eager_init(false);
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+    assert!(container.get_bean::<C>().is_ok());
+    assert!(container.get_bean::<GreetingService>().is_ok());
+}
+
+#[test]
+fn test_concurrent_first_lookups_construct_singleton_once() {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Barrier;
+
+    struct Expensive;
+    static CONSTRUCTIONS: AtomicUsize = AtomicUsize::new(0);
+
+    fn construct_expensive(_: BeanProviderRef) -> Result<BeanInstance, ConstructorError> {
+        CONSTRUCTIONS.fetch_add(1, Ordering::SeqCst);
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        Ok(Arc::new(Expensive))
+    }
+
+    let container = IocContainer::new();
+    container.set_eager_init(false);
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+    container
+        .register_bean_definition(BeanDefinition::new(
+            "expensive".to_string(),
+            TypeId::of::<Expensive>(),
+            construct_expensive,
+        ))
+        .unwrap();
+
+    let barrier = Arc::new(Barrier::new(8));
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let container = container.clone();
+            let barrier = barrier.clone();
+            std::thread::spawn(move || {
+                barrier.wait();
+                container.get_bean::<Expensive>()
+            })
+        })
+        .collect();
+    let beans: Vec<Arc<Expensive>> = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap().expect("concurrent lookup failed"))
+        .collect();
+
+    assert_eq!(CONSTRUCTIONS.load(Ordering::SeqCst), 1);
+    assert!(beans.iter().all(|bean| Arc::ptr_eq(bean, &beans[0])));
+}
+
+#[test]
+fn test_concurrent_lookups_of_mutual_dependencies_do_not_deadlock() {
+    use std::sync::Barrier;
+
+    struct A;
+    struct B;
+
+    // Neither factory declares the bean it looks up, so no cycle is found before creation.
+    fn register_mutual_factories(container: &IocContainer) {
+        container
+            .register_factory("a", |provider: &BeanProviderRef| {
+                std::thread::sleep(std::time::Duration::from_millis(20));
+                resolve_bean::<B>(&**provider)?;
+                Ok(A)
+            })
+            .unwrap();
+        container
+            .register_factory("b", |provider: &BeanProviderRef| {
+                std::thread::sleep(std::time::Duration::from_millis(20));
+                resolve_bean::<A>(&**provider)?;
+                Ok(B)
+            })
+            .unwrap();
+    }
+
+    let sequential = IocContainer::new();
+    sequential.set_eager_init(false);
+    register_mutual_factories(&sequential);
+    sequential
+        .initialize()
+        .expect("Failed to initialize container");
+    let expected = [
+        sequential.get_bean::<A>().err().unwrap().to_string(),
+        sequential.get_bean::<B>().err().unwrap().to_string(),
+    ];
+    assert!(expected[0].contains("a -> b -> a"), "{}", expected[0]);
+
+    let container = IocContainer::new();
+    container.set_eager_init(false);
+    register_mutual_factories(&container);
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+    let barrier = Arc::new(Barrier::new(2));
+    let lookup_a = {
+        let (container, barrier) = (container.clone(), barrier.clone());
+        std::thread::spawn(move || {
+            barrier.wait();
+            container.get_bean::<A>().err().map(|e| e.to_string())
+        })
+    };
+    let lookup_b = std::thread::spawn(move || {
+        barrier.wait();
+        container.get_bean::<B>().err().map(|e| e.to_string())
+    });
+
+    // Each thread reports the cycle a single thread would have reported.
+    assert_eq!(
+        [lookup_a.join().unwrap(), lookup_b.join().unwrap()],
+        expected.map(Some)
+    );
+}
+
+#[test]
+fn test_nested_failure_keeps_root_cause_and_path() {
+    use std::error::Error;
+
+    struct Api;
+    struct Service;
+    struct Broken;
+
+    fn construct_api(provider: BeanProviderRef) -> Result<BeanInstance, ConstructorError> {
+        resolve_bean::<Service>(&*provider)?;
+        Ok(Arc::new(Api))
+    }
+
+    fn construct_service(provider: BeanProviderRef) -> Result<BeanInstance, ConstructorError> {
+        resolve_bean::<Broken>(&*provider)?;
+        Ok(Arc::new(Service))
+    }
+
+    let container = IocContainer::new();
+    container.set_eager_init(false);
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+    for (name, type_id, constructor) in [
+        (
+            "api",
+            TypeId::of::<Api>(),
+            construct_api as summer_core::BeanConstructor,
+        ),
+        ("service", TypeId::of::<Service>(), construct_service),
+        (
+            "broken",
+            TypeId::of::<Broken>(),
+            construct_with_missing_dependency,
+        ),
+    ] {
+        container
+            .register_bean_definition(BeanDefinition::new(name.to_string(), type_id, constructor))
+            .unwrap();
+    }
+
+    let err = container.get_bean::<Api>().err().unwrap();
+    assert_eq!(err.resolution_path(), ["api", "service", "broken"]);
+    assert!(matches!(
+        err.source()
+            .and_then(|e| e.downcast_ref::<ConstructorError>()),
+        Some(ConstructorError::DependencyFailed { .. })
+    ));
+
+    let mut root: &dyn Error = &err;
+    while let Some(source) = root.source() {
+        root = source;
+    }
+    assert!(matches!(
+        root.downcast_ref::<ConstructorError>(),
+        Some(ConstructorError::BeanNotFoundByType { bean_type, .. })
+            if *bean_type == TypeId::of::<UnregisteredDependency>()
+    ));
+    assert!(err.to_string().contains("api -> service -> broken"));
+    assert!(err.to_string().contains("graph::UnregisteredDependency"));
+}
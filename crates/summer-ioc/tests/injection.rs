@@ -0,0 +1,472 @@
+//! Constructor, field, trait object, qualified, collection and deferred injection.
+
+use std::any::TypeId;
+use std::sync::Arc;
+use summer_core::{BeanInstance, BeanProviderRef, ConstructorError, Lazy, ObjectProvider};
+use summer_ioc::{BeanDefinition, IocContainer, IocError};
+use summer_macros::{component, constructor};
+
+#[component]
+#[derive(Default)]
+struct GreetingRepository;
+
+impl GreetingRepository {
+    fn greeting(&self) -> &'static str {
+        "hello"
+    }
+}
+
+#[component]
+struct GreetingService {
+    repository: Arc<GreetingRepository>,
+}
+
+#[constructor]
+impl GreetingService {
+    fn new(repository: Arc<GreetingRepository>) -> Self {
+        GreetingService { repository }
+    }
+
+    fn greet(&self, name: &str) -> String {
+        format!("{}, {}", self.repository.greeting(), name)
+    }
+}
+
+/// Not a component: used to exercise optional injection of a missing bean.
+struct UnregisteredDependency;
+
+#[component]
+struct GreetingController {
+    #[autowired]
+    service: Arc<GreetingService>,
+    #[autowired]
+    audit_log: Option<Arc<UnregisteredDependency>>,
+    prefix: String,
+}
+
+trait UserRepository: Send + Sync {
+    fn find_name(&self, id: u32) -> String;
+}
+
+#[component(provides = dyn UserRepository)]
+#[derive(Default)]
+struct InMemoryUserRepository;
+
+impl UserRepository for InMemoryUserRepository {
+    fn find_name(&self, id: u32) -> String {
+        format!("user-{}", id)
+    }
+}
+
+#[component]
+struct UserDirectory {
+    repository: Arc<dyn UserRepository>,
+}
+
+#[constructor]
+impl UserDirectory {
+    fn new(repository: Arc<dyn UserRepository>) -> Self {
+        UserDirectory { repository }
+    }
+}
+
+trait PaymentGateway: Send + Sync {
+    fn id(&self) -> &'static str;
+}
+
+#[component(provides = dyn PaymentGateway, primary)]
+#[derive(Default)]
+struct StandardGateway;
+
+impl PaymentGateway for StandardGateway {
+    fn id(&self) -> &'static str {
+        "standard"
+    }
+}
+
+#[component(provides = dyn PaymentGateway, qualifier = "fast")]
+#[derive(Default)]
+struct FastGateway;
+
+impl PaymentGateway for FastGateway {
+    fn id(&self) -> &'static str {
+        "fast"
+    }
+}
+
+#[component]
+struct CheckoutService {
+    #[autowired]
+    default_gateway: Arc<dyn PaymentGateway>,
+    #[autowired]
+    #[qualifier("fast")]
+    fast_gateway: Arc<dyn PaymentGateway>,
+}
+
+#[component]
+struct RefundService {
+    gateway: Arc<dyn PaymentGateway>,
+}
+
+#[constructor]
+impl RefundService {
+    fn new(#[qualifier("FastGateway")] gateway: Arc<dyn PaymentGateway>) -> Self {
+        RefundService { gateway }
+    }
+}
+
+#[component]
+struct ExpressCheckout {
+    #[autowired]
+    #[qualifier(FastGateway::BEAN)]
+    gateway: Arc<dyn PaymentGateway>,
+    #[autowired]
+    #[qualifier(GreetingService::BEAN)]
+    greetings: Arc<GreetingService>,
+}
+
+#[component]
+struct ExpressRefund {
+    gateway: Arc<dyn PaymentGateway>,
+}
+
+#[constructor]
+impl ExpressRefund {
+    fn new(#[qualifier(FastGateway::BEAN)] gateway: Arc<dyn PaymentGateway>) -> Self {
+        ExpressRefund { gateway }
+    }
+}
+
+trait Validator: Send + Sync {
+    fn name(&self) -> &'static str;
+}
+
+#[component(provides = dyn Validator)]
+#[order(2)]
+#[derive(Default)]
+struct LengthValidator;
+
+impl Validator for LengthValidator {
+    fn name(&self) -> &'static str {
+        "length"
+    }
+}
+
+#[component(provides = dyn Validator)]
+#[order(1)]
+#[derive(Default)]
+struct NotEmptyValidator;
+
+impl Validator for NotEmptyValidator {
+    fn name(&self) -> &'static str {
+        "not-empty"
+    }
+}
+
+/// Unordered, so it comes after every ordered validator.
+#[component(provides = dyn Validator)]
+#[derive(Default)]
+struct AsciiValidator;
+
+impl Validator for AsciiValidator {
+    fn name(&self) -> &'static str {
+        "ascii"
+    }
+}
+
+#[component]
+struct ValidationPipeline {
+    validators: Vec<Arc<dyn Validator>>,
+    by_name: std::collections::HashMap<String, Arc<dyn Validator>>,
+}
+
+#[constructor]
+impl ValidationPipeline {
+    fn new(
+        validators: Vec<Arc<dyn Validator>>,
+        by_name: std::collections::HashMap<String, Arc<dyn Validator>>,
+    ) -> Self {
+        ValidationPipeline {
+            validators,
+            by_name,
+        }
+    }
+}
+
+#[component(scope = "prototype")]
+#[derive(Default)]
+struct RequestContext;
+
+/// Refers back to `CustomerService`, which depends on it eagerly.
+#[component]
+struct OrderService {
+    #[autowired]
+    customers: Lazy<CustomerService>,
+    #[autowired]
+    users: Lazy<dyn UserRepository>,
+    #[autowired]
+    request_contexts: ObjectProvider<RequestContext>,
+}
+
+#[component]
+struct CustomerService {
+    orders: Arc<OrderService>,
+}
+
+#[constructor]
+impl CustomerService {
+    fn new(orders: Arc<OrderService>) -> Self {
+        CustomerService { orders }
+    }
+}
+
+#[test]
+fn test_constructor_injection() {
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    let service = container.get_bean::<GreetingService>().unwrap();
+    let repository = container.get_bean::<GreetingRepository>().unwrap();
+
+    assert_eq!(service.greet("summer"), "hello, summer");
+    assert!(Arc::ptr_eq(&service.repository, &repository));
+}
+
+#[test]
+fn test_field_injection() {
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    let controller = container.get_bean::<GreetingController>().unwrap();
+    let service = container.get_bean::<GreetingService>().unwrap();
+
+    assert!(Arc::ptr_eq(&controller.service, &service));
+    assert!(controller.audit_log.is_none());
+    assert!(controller.prefix.is_empty());
+}
+
+#[test]
+fn test_trait_object_injection() {
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    let repository = container.get_bean_dyn::<dyn UserRepository>().unwrap();
+    let concrete = container.get_bean::<InMemoryUserRepository>().unwrap();
+    let directory = container.get_bean::<UserDirectory>().unwrap();
+
+    assert_eq!(repository.find_name(7), "user-7");
+    assert_eq!(directory.repository.find_name(1), "user-1");
+    // Every view shares the single underlying singleton.
+    assert!(std::ptr::eq(
+        Arc::as_ptr(&repository) as *const (),
+        Arc::as_ptr(&concrete) as *const ()
+    ));
+    assert!(std::ptr::eq(
+        Arc::as_ptr(&directory.repository) as *const (),
+        Arc::as_ptr(&concrete) as *const ()
+    ));
+}
+
+#[test]
+fn test_primary_and_qualifier_disambiguation() {
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    let primary = container.get_bean_dyn::<dyn PaymentGateway>().unwrap();
+    let fast = container
+        .get_qualified_bean_dyn::<dyn PaymentGateway>("fast")
+        .unwrap();
+    assert_eq!(primary.id(), "standard");
+    assert_eq!(fast.id(), "fast");
+
+    let checkout = container.get_bean::<CheckoutService>().unwrap();
+    assert_eq!(checkout.default_gateway.id(), "standard");
+    assert_eq!(checkout.fast_gateway.id(), "fast");
+
+    // Bean names act as implicit qualifiers.
+    let refunds = container.get_bean::<RefundService>().unwrap();
+    assert_eq!(refunds.gateway.id(), "fast");
+
+    assert!(matches!(
+        container.get_qualified_bean_dyn::<dyn PaymentGateway>("missing"),
+        Err(IocError::BeanNotFoundByQualifier { .. })
+    ));
+}
+
+#[test]
+fn test_multiple_beans_without_primary_are_ambiguous() {
+    struct Shared;
+
+    fn construct_shared(_: BeanProviderRef) -> Result<BeanInstance, ConstructorError> {
+        Ok(Arc::new(Shared))
+    }
+
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+    for (name, qualifier) in [("first", "a"), ("second", "b")] {
+        container
+            .register_bean_definition(
+                BeanDefinition::new(name.to_string(), TypeId::of::<Shared>(), construct_shared)
+                    .with_qualifier(qualifier),
+            )
+            .unwrap();
+    }
+
+    assert!(matches!(
+        container.get_bean::<Shared>(),
+        Err(IocError::MultipleBeansFound(_))
+    ));
+    let second = container.get_qualified_bean::<Shared>("b").unwrap();
+    let by_name = container.get_bean_by_name::<Shared>("second").unwrap();
+    assert!(Arc::ptr_eq(&second, &by_name));
+}
+
+#[test]
+fn test_collection_injection() {
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    let pipeline = container.get_bean::<ValidationPipeline>().unwrap();
+    let names: Vec<&str> = pipeline.validators.iter().map(|v| v.name()).collect();
+    assert_eq!(names, ["not-empty", "length", "ascii"]);
+    assert_eq!(pipeline.by_name["LengthValidator"].name(), "length");
+    assert_eq!(pipeline.by_name.len(), 3);
+
+    let validators = container.get_beans_of_type_dyn::<dyn Validator>().unwrap();
+    assert!(Arc::ptr_eq(&validators[0], &pipeline.validators[0]));
+    assert_eq!(
+        container
+            .get_beans_of_type::<LengthValidator>()
+            .unwrap()
+            .len(),
+        1
+    );
+    assert!(container
+        .get_beans_of_type::<UnregisteredDependency>()
+        .unwrap()
+        .is_empty());
+    assert!(matches!(
+        container.get_bean_dyn::<dyn Validator>(),
+        Err(IocError::MultipleBeansFound(_))
+    ));
+
+    let graph = container.dependency_graph();
+    let mut pipeline_deps: Vec<&str> = graph.dependencies_of("ValidationPipeline").collect();
+    pipeline_deps.dedup();
+    assert_eq!(
+        pipeline_deps,
+        ["AsciiValidator", "LengthValidator", "NotEmptyValidator"]
+    );
+}
+
+#[test]
+fn test_lazy_and_provider_injection() {
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    // The cycle through the lazy handle is legitimate and does not fail startup.
+    let orders = container.get_bean::<OrderService>().unwrap();
+    let customers = orders.customers.get().unwrap();
+    assert!(Arc::ptr_eq(&customers.orders, &orders));
+    assert!(Arc::ptr_eq(&customers, &orders.customers.get().unwrap()));
+    assert!(!orders.users.get().unwrap().find_name(1).is_empty());
+
+    let first = orders.request_contexts.get().unwrap();
+    let second = orders.request_contexts.get().unwrap();
+    assert!(!Arc::ptr_eq(&first, &second));
+}
+
+#[test]
+fn test_errors_name_types_and_suggest_beans() {
+    mod legacy {
+        pub struct GreetingService;
+    }
+
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    match container.get_bean_by_name::<GreetingService>("GreetingServise") {
+        Err(e @ IocError::BeanNotFoundByName { .. }) => {
+            assert!(e.to_string().ends_with("(did you mean 'GreetingService'?)"))
+        }
+        other => panic!("expected BeanNotFoundByName, got {:?}", other.err()),
+    }
+
+    let err = container
+        .get_bean_by_name::<GreetingRepository>("GreetingService")
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains(&format!(
+        "Requested {}, but found {}",
+        std::any::type_name::<GreetingRepository>(),
+        std::any::type_name::<GreetingService>()
+    )));
+
+    match container.get_bean::<legacy::GreetingService>() {
+        Err(IocError::BeanNotFoundByType {
+            bean_type,
+            suggestions,
+        }) => {
+            assert!(bean_type.to_string().ends_with("legacy::GreetingService"));
+            assert_eq!(
+                suggestions.as_slice(),
+                [format!(
+                    "'GreetingService' of type {}",
+                    std::any::type_name::<GreetingService>()
+                )]
+            );
+        }
+        other => panic!("expected BeanNotFoundByType, got {:?}", other.err()),
+    }
+
+    let err = container
+        .get_qualified_bean::<GreetingRepository>("greeting")
+        .err()
+        .unwrap();
+    assert!(err
+        .to_string()
+        .ends_with("qualifier 'greeting' (did you mean 'GreetingRepository'?)"));
+}
+
+#[test]
+fn test_typed_bean_keys() {
+    assert_eq!(GreetingService::BEAN.name(), "GreetingService");
+
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+    let service = container.get(GreetingService::BEAN).unwrap();
+    assert!(Arc::ptr_eq(
+        &service,
+        &container.get_bean::<GreetingService>().unwrap()
+    ));
+
+    let checkout = container.get(ExpressCheckout::BEAN).unwrap();
+    assert_eq!(checkout.gateway.id(), "fast");
+    assert!(Arc::ptr_eq(&checkout.greetings, &service));
+
+    let refund = container.get(ExpressRefund::BEAN).unwrap();
+    assert_eq!(refund.gateway.id(), "fast");
+
+    let graph = container.dependency_graph();
+    assert!(graph
+        .dependencies_of("ExpressCheckout")
+        .any(|bean| bean == "FastGateway"));
+}
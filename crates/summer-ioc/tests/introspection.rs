@@ -0,0 +1,96 @@
+//! Bean descriptors and the container report.
+
+use std::sync::Arc;
+use summer_ioc::{BeanDefinition, BeanDescriptor, BeanScope, IocContainer};
+use summer_macros::{component, constructor};
+
+#[component]
+#[derive(Default)]
+struct GreetingRepository;
+
+#[component]
+struct GreetingService {
+    _repository: Arc<GreetingRepository>,
+}
+
+#[constructor]
+impl GreetingService {
+    fn new(repository: Arc<GreetingRepository>) -> Self {
+        GreetingService {
+            _repository: repository,
+        }
+    }
+}
+
+#[component(scope = "prototype")]
+#[derive(Default)]
+struct RequestContext;
+
+/// Not a component: used to exercise failed dependency resolution.
+struct UnregisteredDependency;
+
+/// Would fail eager startup if it were not lazy.
+#[component(lazy)]
+struct ReportGenerator {
+    #[autowired]
+    _source: Arc<UnregisteredDependency>,
+}
+
+#[test]
+fn test_bean_descriptors_and_report() {
+    struct Clock;
+
+    let container = IocContainer::new();
+    container
+        .register_bean_definition(
+            BeanDefinition::for_factory("clock", |_| Ok(Clock)).with_lazy(true),
+        )
+        .unwrap();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    let descriptors: Vec<BeanDescriptor> = container.bean_descriptors().collect();
+    let service = descriptors
+        .iter()
+        .find(|d| d.name == "GreetingService")
+        .unwrap();
+    assert!(service
+        .type_name
+        .ends_with("introspection::GreetingService"));
+    assert_eq!(service.scope, BeanScope::Singleton);
+    assert!(service.instantiated && service.created_at.is_some());
+    assert_eq!(service.dependencies, ["GreetingRepository"]);
+
+    let clock = descriptors.iter().find(|d| d.name == "clock").unwrap();
+    assert!(clock.type_name.ends_with("Clock"));
+    assert!(!clock.instantiated && clock.created_at.is_none());
+
+    // A lazy singleton counts as instantiated once looked up; a prototype never does.
+    container.get_bean::<Clock>().unwrap();
+    container.get_bean::<RequestContext>().unwrap();
+    let descriptors: Vec<BeanDescriptor> = container.bean_descriptors().collect();
+    let clock = descriptors.iter().find(|d| d.name == "clock").unwrap();
+    assert!(clock.instantiated && clock.created_at.is_some());
+    let request_context = descriptors
+        .iter()
+        .find(|d| d.name == "RequestContext")
+        .unwrap();
+    assert_eq!(request_context.scope, BeanScope::Prototype);
+    assert!(!request_context.instantiated && request_context.created_at.is_none());
+
+    let report: serde_json::Value = serde_json::from_str(&container.report().to_json()).unwrap();
+    assert_eq!(report["initialized"], true);
+    let beans = report["beans"].as_array().unwrap();
+    assert_eq!(beans.len(), descriptors.len());
+    let lazy = beans
+        .iter()
+        .find(|bean| bean["name"] == "ReportGenerator")
+        .unwrap();
+    assert_eq!(lazy["scope"], "singleton");
+    assert_eq!(lazy["instantiated"], false);
+    assert!(lazy["created_at"].is_null());
+    assert!(beans
+        .iter()
+        .any(|bean| bean["name"] == "GreetingService" && bean["created_at"].is_u64()));
+}
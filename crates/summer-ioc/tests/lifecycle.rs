@@ -0,0 +1,287 @@
+//! Post-construct/pre-destroy callbacks, container close and eager startup.
+
+use std::any::TypeId;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use summer_core::{resolve_bean, BeanInstance, BeanProviderRef, ConstructorError};
+use summer_ioc::{BeanDefinition, IocContainer, IocError};
+use summer_macros::{component, constructor, post_construct, pre_destroy};
+
+#[component]
+#[derive(Default)]
+struct ShutdownLog {
+    events: parking_lot::Mutex<Vec<&'static str>>,
+}
+
+#[component]
+struct ConnectionPool {
+    #[autowired]
+    log: Arc<ShutdownLog>,
+    open: AtomicBool,
+}
+
+impl ConnectionPool {
+    #[post_construct]
+    fn connect(&self) {
+        self.open.store(true, Ordering::SeqCst);
+    }
+
+    #[pre_destroy]
+    fn disconnect(&self) {
+        self.log.events.lock().push("pool");
+    }
+}
+
+/// Lazy, so that the synchronous `initialize` calls in this file do not try to create it.
+#[component(lazy)]
+struct MessageQueue {
+    #[autowired]
+    log: Arc<ShutdownLog>,
+    open: AtomicBool,
+}
+
+impl MessageQueue {
+    #[post_construct]
+    async fn connect(&self) {
+        self.open.store(true, Ordering::SeqCst);
+    }
+
+    #[pre_destroy]
+    async fn disconnect(&self) {
+        self.log.events.lock().push("queue");
+    }
+}
+
+#[component]
+struct SessionRepository {
+    pool: Arc<ConnectionPool>,
+    log: Arc<ShutdownLog>,
+    ready: AtomicBool,
+}
+
+#[constructor]
+impl SessionRepository {
+    fn new(pool: Arc<ConnectionPool>, log: Arc<ShutdownLog>) -> Self {
+        SessionRepository {
+            pool,
+            log,
+            ready: AtomicBool::new(false),
+        }
+    }
+
+    #[post_construct]
+    fn init(&self) -> Result<(), String> {
+        if !self.pool.open.load(Ordering::SeqCst) {
+            return Err("connection pool is not open".to_string());
+        }
+        self.ready.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    #[pre_destroy]
+    fn shutdown(&self) {
+        self.log.events.lock().push("repository");
+    }
+}
+
+/// Not a component: used to exercise failed dependency resolution.
+struct UnregisteredDependency;
+
+#[component(lazy)]
+struct ReportGenerator {
+    #[autowired]
+    _source: Arc<UnregisteredDependency>,
+}
+
+fn construct_with_missing_dependency(
+    provider: BeanProviderRef,
+) -> Result<BeanInstance, ConstructorError> {
+    let dependency = resolve_bean::<UnregisteredDependency>(&*provider)?;
+    Ok(dependency)
+}
+
+#[test]
+fn test_lifecycle_callbacks_and_close() {
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    let repository = container.get_bean::<SessionRepository>().unwrap();
+    assert!(repository.ready.load(Ordering::SeqCst));
+    let log = container.get_bean::<ShutdownLog>().unwrap();
+
+    container.close().expect("Failed to close container");
+    // Dependents are destroyed before their dependencies.
+    assert_eq!(*log.events.lock(), ["repository", "pool"]);
+    assert!(matches!(
+        container.get_bean::<SessionRepository>().err(),
+        Some(IocError::ContainerNotInitialized)
+    ));
+}
+
+#[tokio::test]
+async fn test_close_async_awaits_destroy_callbacks() {
+    struct Failing;
+
+    let container = IocContainer::new();
+    container
+        .initialize_async()
+        .await
+        .expect("Failed to initialize container");
+    container
+        .register_bean_definition(
+            BeanDefinition::new("failing".to_string(), TypeId::of::<Failing>(), |_| {
+                Ok(Arc::new(Failing))
+            })
+            .with_post_construct(summer_core::LifecycleCallback::Sync(|_| {
+                Err(ConstructorError::BaseMsgError("boom".to_string()))
+            })),
+        )
+        .unwrap();
+    assert!(matches!(
+        container.get_bean::<Failing>().err(),
+        Some(IocError::LifecycleCallbackError { .. })
+    ));
+
+    let queue = container.get_bean_async::<MessageQueue>().await.unwrap();
+    assert!(queue.open.load(Ordering::SeqCst));
+
+    container
+        .close_async()
+        .await
+        .expect("Failed to close container");
+    assert_eq!(*queue.log.events.lock(), ["queue", "repository", "pool"]);
+}
+
+#[tokio::test]
+async fn test_async_callbacks_are_rejected_on_the_sync_path() {
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    match container.get_bean::<MessageQueue>() {
+        Err(e @ IocError::LifecycleCallbackError { .. }) => {
+            assert!(e.to_string().contains("get_bean_async"), "{}", e)
+        }
+        other => panic!("expected a LifecycleCallbackError, got {:?}", other.err()),
+    }
+
+    // The other callbacks still run when a synchronous close meets an async one.
+    let queue = container.get_bean_async::<MessageQueue>().await.unwrap();
+    match container.close() {
+        Err(e @ IocError::LifecycleCallbackError { .. }) => {
+            assert!(e.to_string().contains("close_async"), "{}", e)
+        }
+        other => panic!("expected a LifecycleCallbackError, got {:?}", other),
+    }
+    assert_eq!(*queue.log.events.lock(), ["repository", "pool"]);
+}
+
+#[test]
+fn test_closed_container_can_be_reinitialized() {
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+    let first = container.get_bean::<SessionRepository>().unwrap();
+    container.close().expect("Failed to close container");
+
+    container
+        .initialize()
+        .expect("Failed to reinitialize container");
+    let second = container.get_bean::<SessionRepository>().unwrap();
+    assert!(second.ready.load(Ordering::SeqCst));
+    assert!(!Arc::ptr_eq(&first, &second));
+}
+
+#[test]
+fn test_eager_startup_reports_every_failure() {
+    struct Broken;
+    struct AlsoBroken;
+    struct Deferred;
+
+    let container = IocContainer::new();
+    for (name, type_id) in [
+        ("broken", TypeId::of::<Broken>()),
+        ("also_broken", TypeId::of::<AlsoBroken>()),
+    ] {
+        container
+            .register_bean_definition(BeanDefinition::new(
+                name.to_string(),
+                type_id,
+                construct_with_missing_dependency,
+            ))
+            .unwrap();
+    }
+    container
+        .register_bean_definition(
+            BeanDefinition::new(
+                "deferred".to_string(),
+                TypeId::of::<Deferred>(),
+                construct_with_missing_dependency,
+            )
+            .with_lazy(true),
+        )
+        .unwrap();
+
+    match container.initialize() {
+        Err(IocError::StartupFailed(failures)) => {
+            let names: Vec<&str> = failures.iter().map(|(name, _)| name.as_str()).collect();
+            assert_eq!(names, ["also_broken", "broken"]);
+        }
+        other => panic!("expected a startup failure, got {:?}", other),
+    }
+
+    // The failed startup closed the container; with the broken beans fixed it starts again.
+    container
+        .replace_bean_definition(BeanDefinition::for_factory("broken", |_| Ok(Broken)))
+        .unwrap();
+    container
+        .replace_bean_definition(BeanDefinition::for_factory("also_broken", |_| {
+            Ok(AlsoBroken)
+        }))
+        .unwrap();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+    assert!(container.get_bean::<AlsoBroken>().is_ok());
+    assert!(container.get_bean::<SessionRepository>().is_ok());
+}
+
+#[test]
+fn test_lazy_singleton_fails_on_first_lookup() {
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    assert!(matches!(
+        container.get_bean::<ReportGenerator>().err(),
+        Some(IocError::InstantiationError { .. })
+    ));
+}
+
+#[test]
+fn test_failed_construction_can_be_retried() {
+    struct Broken;
+
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+    container
+        .register_bean_definition(BeanDefinition::new(
+            "broken".to_string(),
+            TypeId::of::<Broken>(),
+            construct_with_missing_dependency,
+        ))
+        .unwrap();
+
+    // A failed constructor must not leave the bean marked as "in creation".
+    for _ in 0..2 {
+        let err = container.get_bean_by_name::<Broken>("broken").err();
+        assert!(matches!(err, Some(IocError::InstantiationError { .. })));
+    }
+}
@@ -0,0 +1,149 @@
+//! Bean post-processors and the aware callbacks.
+
+use std::sync::{Arc, OnceLock};
+use summer_core::{
+    resolve_bean, BeanInstance, BeanNameAware, BeanProviderRef, ConstructorError, ContainerAware,
+    WeakBeanProviderRef,
+};
+use summer_ioc::{BeanPostProcessor, IocContainer, IocError};
+use summer_macros::component;
+
+#[component]
+#[derive(Default)]
+struct GreetingRepository;
+
+impl GreetingRepository {
+    fn greeting(&self) -> &'static str {
+        "hello"
+    }
+}
+
+#[component(bean_name_aware, container_aware)]
+#[derive(Default)]
+struct ServiceLocator {
+    name: OnceLock<String>,
+    container: OnceLock<WeakBeanProviderRef>,
+}
+
+impl BeanNameAware for ServiceLocator {
+    fn set_bean_name(&self, name: &str) {
+        self.name.set(name.to_string()).unwrap();
+    }
+}
+
+impl ContainerAware for ServiceLocator {
+    fn set_container(&self, container: WeakBeanProviderRef) {
+        let _ = self.container.set(container);
+    }
+}
+
+#[test]
+fn test_bean_post_processors_replace_beans_of_the_same_type() {
+    struct Counter {
+        value: u32,
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        seen: parking_lot::Mutex<Vec<String>>,
+    }
+    impl BeanPostProcessor for Recorder {
+        fn before_init(
+            &self,
+            bean_name: &str,
+            bean: BeanInstance,
+        ) -> Result<BeanInstance, ConstructorError> {
+            self.seen.lock().push(format!("before:{}", bean_name));
+            Ok(bean)
+        }
+        fn after_init(
+            &self,
+            bean_name: &str,
+            bean: BeanInstance,
+        ) -> Result<BeanInstance, ConstructorError> {
+            self.seen.lock().push(format!("after:{}", bean_name));
+            Ok(bean)
+        }
+    }
+
+    struct Scaling;
+    impl BeanPostProcessor for Scaling {
+        fn after_init(
+            &self,
+            _bean_name: &str,
+            bean: BeanInstance,
+        ) -> Result<BeanInstance, ConstructorError> {
+            Ok(match bean.downcast_ref::<Counter>() {
+                Some(counter) => Arc::new(Counter {
+                    value: counter.value * 10,
+                }),
+                None => bean,
+            })
+        }
+    }
+
+    /// Tries to swap the bean for an instance of another type, which is rejected.
+    struct Replacing;
+    impl BeanPostProcessor for Replacing {
+        fn before_init(
+            &self,
+            bean_name: &str,
+            bean: BeanInstance,
+        ) -> Result<BeanInstance, ConstructorError> {
+            if bean_name == "counter" {
+                return Ok(Arc::new("not a counter"));
+            }
+            Ok(bean)
+        }
+    }
+
+    let recorder = Arc::new(Recorder::default());
+    let container = IocContainer::new();
+    container.register_bean_post_processor(recorder.clone());
+    container.register_bean_post_processor(Arc::new(Scaling));
+    container
+        .register_factory("counter", |_: &BeanProviderRef| Ok(Counter { value: 4 }))
+        .unwrap();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    assert_eq!(container.get_bean::<Counter>().unwrap().value, 40);
+    let seen = recorder.seen.lock();
+    let before = seen.iter().position(|s| s == "before:counter").unwrap();
+    let after = seen.iter().position(|s| s == "after:counter").unwrap();
+    assert!(before < after);
+    drop(seen);
+
+    let container = IocContainer::new();
+    container.set_eager_init(false);
+    container.register_bean_post_processor(Arc::new(Replacing));
+    container
+        .register_factory("counter", |_: &BeanProviderRef| Ok(Counter { value: 4 }))
+        .unwrap();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+    assert!(matches!(
+        container.get_bean::<Counter>(),
+        Err(IocError::TypeMismatchError { .. })
+    ));
+}
+
+#[test]
+fn test_aware_beans_receive_name_and_container() {
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    let locator = container.get_bean::<ServiceLocator>().unwrap();
+    assert_eq!(locator.name.get().unwrap(), "ServiceLocator");
+    let handle = locator.container.get().unwrap().upgrade().unwrap();
+    assert_eq!(
+        Arc::as_ptr(&handle) as *const (),
+        Arc::as_ptr(&container) as *const ()
+    );
+    let greetings = resolve_bean::<GreetingRepository>(&*handle).unwrap();
+    assert_eq!(greetings.greeting(), "hello");
+}
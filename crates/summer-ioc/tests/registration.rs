@@ -0,0 +1,235 @@
+//! Programmatic registration, child containers and bean overriding.
+
+use std::any::TypeId;
+use std::sync::Arc;
+use summer_core::{resolve_bean, BeanProviderRef};
+use summer_ioc::{BeanDefinition, BeanOverridePolicy, IocContainer, IocError};
+use summer_macros::{component, constructor, pre_destroy};
+
+#[component]
+#[derive(Default)]
+struct GreetingRepository;
+
+impl GreetingRepository {
+    fn greeting(&self) -> &'static str {
+        "hello"
+    }
+}
+
+#[component]
+struct GreetingService {
+    repository: Arc<GreetingRepository>,
+}
+
+#[constructor]
+impl GreetingService {
+    fn new(repository: Arc<GreetingRepository>) -> Self {
+        GreetingService { repository }
+    }
+
+    fn greet(&self, name: &str) -> String {
+        format!("{}, {}", self.repository.greeting(), name)
+    }
+}
+
+#[component]
+#[derive(Default)]
+struct ShutdownLog {
+    events: parking_lot::Mutex<Vec<&'static str>>,
+}
+
+impl ShutdownLog {
+    #[pre_destroy]
+    fn destroy(&self) {
+        self.events.lock().push("log");
+    }
+}
+
+trait UserRepository: Send + Sync {
+    fn find_name(&self, id: u32) -> String;
+}
+
+#[component(provides = dyn UserRepository)]
+#[derive(Default)]
+struct InMemoryUserRepository;
+
+impl UserRepository for InMemoryUserRepository {
+    fn find_name(&self, id: u32) -> String {
+        format!("user-{}", id)
+    }
+}
+
+#[component]
+struct UserDirectory {
+    repository: Arc<dyn UserRepository>,
+}
+
+#[constructor]
+impl UserDirectory {
+    fn new(repository: Arc<dyn UserRepository>) -> Self {
+        UserDirectory { repository }
+    }
+}
+
+#[test]
+fn test_register_singleton_and_factory() {
+    struct DatabaseConfig {
+        url: String,
+    }
+    struct DatabaseClient {
+        url: String,
+        label: String,
+    }
+
+    let config = Arc::new(DatabaseConfig {
+        url: "postgres://localhost/app".to_string(),
+    });
+    let label = "primary".to_string();
+
+    let container = IocContainer::new();
+    container
+        .register_singleton("databaseConfig", config.clone())
+        .unwrap();
+    container
+        .register_factory("databaseClient", move |provider: &BeanProviderRef| {
+            let config = resolve_bean::<DatabaseConfig>(&**provider)?;
+            Ok(DatabaseClient {
+                url: config.url.clone(),
+                label: label.clone(),
+            })
+        })
+        .unwrap();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    assert!(Arc::ptr_eq(
+        &container.get_bean::<DatabaseConfig>().unwrap(),
+        &config
+    ));
+    let client = container.get_bean::<DatabaseClient>().unwrap();
+    assert_eq!(client.url, "postgres://localhost/app");
+    assert_eq!(client.label, "primary");
+    assert!(Arc::ptr_eq(
+        &client,
+        &container
+            .get_bean_by_name::<DatabaseClient>("databaseClient")
+            .unwrap()
+    ));
+}
+
+#[test]
+fn test_child_container_falls_back_to_parent() {
+    struct TenantGreeter {
+        greeting: String,
+    }
+
+    let root = IocContainer::new();
+    root.initialize().expect("Failed to initialize container");
+
+    let child = IocContainer::new_child(root.clone());
+    let tenant_repository = Arc::new(GreetingRepository);
+    child
+        .register_singleton("GreetingRepository", tenant_repository.clone())
+        .unwrap();
+    child
+        .register_factory("tenantGreeter", |provider: &BeanProviderRef| {
+            // Resolved from the parent: the child does not define GreetingService.
+            let service = resolve_bean::<GreetingService>(&**provider)?;
+            Ok(TenantGreeter {
+                greeting: service.greet("tenant"),
+            })
+        })
+        .unwrap();
+    child
+        .initialize()
+        .expect("Failed to initialize child container");
+
+    // Local beans shadow the parent's; everything else comes from the parent.
+    let root_repository = root.get_bean::<GreetingRepository>().unwrap();
+    let child_repository = child.get_bean::<GreetingRepository>().unwrap();
+    assert!(Arc::ptr_eq(&child_repository, &tenant_repository));
+    assert!(!Arc::ptr_eq(&child_repository, &root_repository));
+    assert!(Arc::ptr_eq(
+        &child.get_bean::<GreetingService>().unwrap(),
+        &root.get_bean::<GreetingService>().unwrap()
+    ));
+    assert!(child.get_bean_dyn::<dyn UserRepository>().is_ok());
+    assert!(child
+        .get_bean_by_name::<GreetingService>("GreetingService")
+        .is_ok());
+    assert!(child
+        .get_bean::<TenantGreeter>()
+        .unwrap()
+        .greeting
+        .contains("tenant"));
+    assert!(root.get_bean::<TenantGreeter>().is_err());
+
+    // Closing the child leaves the parent's singletons alive.
+    let log = root.get_bean::<ShutdownLog>().unwrap();
+    child.close().expect("Failed to close child container");
+    assert!(log.events.lock().is_empty());
+    assert!(Arc::ptr_eq(
+        &root.get_bean::<GreetingRepository>().unwrap(),
+        &root_repository
+    ));
+}
+
+#[test]
+fn test_bean_overriding_and_replacement() {
+    struct Clock(&'static str);
+    struct Timer;
+
+    let container = IocContainer::new();
+    container
+        .register_singleton("clock", Arc::new(Clock("system")))
+        .unwrap();
+    assert!(matches!(
+        container.register_singleton("clock", Arc::new(Clock("fixed"))),
+        Err(IocError::BeanAlreadyExists(_))
+    ));
+
+    // Overriding with a bean of another type leaves no stale entry for the old type.
+    container.set_override_policy(BeanOverridePolicy::AllowWithWarning);
+    container
+        .register_singleton("clock", Arc::new(Timer))
+        .unwrap();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+    assert!(matches!(
+        container.get_bean::<Clock>(),
+        Err(IocError::BeanNotFoundByType { .. })
+    ));
+    assert!(container.get_bean::<Timer>().is_ok());
+
+    struct MockUserRepository;
+    impl UserRepository for MockUserRepository {
+        fn find_name(&self, _id: u32) -> String {
+            "mock".to_string()
+        }
+    }
+
+    let container = IocContainer::new();
+    container
+        .replace_bean_definition(
+            BeanDefinition::for_instance("InMemoryUserRepository", Arc::new(MockUserRepository))
+                .with_provided_type(TypeId::of::<dyn UserRepository>(), |instance| {
+                    let mock = instance.downcast::<MockUserRepository>().ok()?;
+                    let view: Arc<dyn UserRepository> = mock;
+                    Some(Arc::new(view))
+                }),
+        )
+        .unwrap();
+    container
+        .replace_bean_with_instance("clock", Arc::new(Clock("fixed")))
+        .unwrap();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    let directory = container.get_bean::<UserDirectory>().unwrap();
+    assert_eq!(directory.repository.find_name(7), "mock");
+    assert!(container.get_bean::<InMemoryUserRepository>().is_err());
+    assert_eq!(container.get_bean::<Clock>().unwrap().0, "fixed");
+}
@@ -0,0 +1,82 @@
+//! Prototype and custom bean scopes.
+
+use std::sync::Arc;
+use summer_core::BeanInstance;
+use summer_ioc::{IocContainer, IocError, Scope};
+use summer_macros::component;
+
+#[component]
+#[derive(Default)]
+struct GreetingRepository;
+
+#[component(scope = "prototype")]
+#[derive(Default)]
+struct RequestContext;
+
+#[component(scope = "tenant")]
+#[derive(Default)]
+struct TenantSession;
+
+/// Caches one instance per bean for the currently active tenant.
+#[derive(Default)]
+struct TenantScope {
+    current_tenant: parking_lot::Mutex<String>,
+    instances: parking_lot::Mutex<std::collections::HashMap<(String, String), BeanInstance>>,
+}
+
+impl Scope for TenantScope {
+    fn get(
+        &self,
+        bean_name: &str,
+        factory: &dyn Fn() -> Result<BeanInstance, IocError>,
+    ) -> Result<BeanInstance, IocError> {
+        let key = (self.current_tenant.lock().clone(), bean_name.to_string());
+        if let Some(instance) = self.instances.lock().get(&key) {
+            return Ok(instance.clone());
+        }
+        let instance = factory()?;
+        self.instances.lock().insert(key, instance.clone());
+        Ok(instance)
+    }
+}
+
+#[test]
+fn test_prototype_scope_creates_new_instances() {
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    let first = container.get_bean::<RequestContext>().unwrap();
+    let second = container.get_bean::<RequestContext>().unwrap();
+    assert!(!Arc::ptr_eq(&first, &second));
+
+    let singleton = container.get_bean::<GreetingRepository>().unwrap();
+    let same = container.get_bean::<GreetingRepository>().unwrap();
+    assert!(Arc::ptr_eq(&singleton, &same));
+}
+
+#[test]
+fn test_custom_scope() {
+    let container = IocContainer::new();
+    container
+        .initialize()
+        .expect("Failed to initialize container");
+
+    let err = container.get_bean::<TenantSession>().err();
+    assert!(matches!(err, Some(IocError::ScopeNotRegistered { .. })));
+
+    let scope = Arc::new(TenantScope::default());
+    container.register_scope("tenant", scope.clone());
+
+    *scope.current_tenant.lock() = "acme".to_string();
+    let acme = container.get_bean::<TenantSession>().unwrap();
+    assert!(Arc::ptr_eq(
+        &acme,
+        &container.get_bean::<TenantSession>().unwrap()
+    ));
+
+    *scope.current_tenant.lock() = "globex".to_string();
+    let globex = container.get_bean::<TenantSession>().unwrap();
+    assert!(!Arc::ptr_eq(&acme, &globex));
+}
@@ -91,6 +91,8 @@ pub fn init(config: &LoggingConfig) -> Result<(), LoggingError> {
     Ok(())
 }
 
+/// Console logging with the default pattern, for tests.
+#[cfg(test)]
 pub fn init_default() -> Result<(), LoggingError> {
     let mut config = LoggingConfig::default();
 
@@ -1,124 +1,1147 @@
 use proc_macro::TokenStream;
 use quote::{quote, quote_spanned, ToTokens};
-use syn::{parse_macro_input, parse_quote, Attribute, FnArg, ImplItem, ItemImpl, ItemStruct, PatType, Type};
+use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, Attribute, ExprPath, Fields, FnArg, GenericArgument, ImplItem, ImplItemFn,
+    ItemImpl, ItemStruct, LitBool, LitStr, PathArguments, Token, Type,
+};
+
+/// Arguments accepted by `#[component(...)]`.
+#[derive(Default)]
+struct ComponentArgs {
+    /// `provides = dyn Trait`: additional types the bean is registered under (repeatable).
+    provides: Vec<Type>,
+    /// `primary`: preferred candidate when several beans match a type.
+    primary: bool,
+    /// `qualifier = "name"`: names that select this bean at injection points (repeatable).
+    qualifiers: Vec<LitStr>,
+    /// `scope = "prototype"`: bean scope name; singleton when omitted.
+    scope: Option<LitStr>,
+    /// `lazy`: create the bean on first lookup instead of at startup.
+    lazy: bool,
+    /// `bean_name_aware`: hand the bean its name through `BeanNameAware`.
+    bean_name_aware: bool,
+    /// `container_aware`: hand the bean a container handle through `ContainerAware`.
+    container_aware: bool,
+}
+
+impl ComponentArgs {
+    fn parse(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("provides") {
+            self.provides.push(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("primary") {
+            self.primary = true;
+            Ok(())
+        } else if meta.path.is_ident("lazy") {
+            self.lazy = true;
+            Ok(())
+        } else if meta.path.is_ident("bean_name_aware") {
+            self.bean_name_aware = true;
+            Ok(())
+        } else if meta.path.is_ident("container_aware") {
+            self.container_aware = true;
+            Ok(())
+        } else if meta.path.is_ident("qualifier") {
+            self.qualifiers.push(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("scope") {
+            let scope: LitStr = meta.value()?.parse()?;
+            if scope.value().is_empty() {
+                return Err(syn::Error::new_spanned(
+                    scope,
+                    "bean scope must not be empty",
+                ));
+            }
+            self.scope = Some(scope);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported #[component] argument"))
+        }
+    }
+}
+
+pub fn anno_component(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut args = ComponentArgs::default();
+    let args_parser = syn::meta::parser(|meta| args.parse(meta));
+    parse_macro_input!(attr with args_parser);
+
+    let mut input_struct = parse_macro_input!(item as ItemStruct);
+
+    // `#[order(n)]` is read here and stripped, like `#[autowired]`.
+    let order = match take_order(&mut input_struct.attrs) {
+        Ok(Some(order)) => quote! { ::std::option::Option::Some(#order) },
+        Ok(None) => quote! { ::std::option::Option::None },
+        Err(err) => {
+            let err = err.to_compile_error();
+            return quote! {
+                #input_struct
+                #err
+            }
+            .into();
+        }
+    };
+
+    // Condition attributes are read and stripped the same way.
+    let conditions = match take_conditions(&mut input_struct.attrs) {
+        Ok(conditions) => conditions,
+        Err(err) => {
+            let err = err.to_compile_error();
+            return quote! {
+                #input_struct
+                #err
+            }
+            .into();
+        }
+    };
+
+    // `#[autowired]` is not a real attribute, so it must be stripped before the struct is emitted.
+    let field_injection = match generate_field_injection(&mut input_struct) {
+        Ok(tokens) => tokens,
+        Err(err) => {
+            // Skip registration so the user only sees the field-level diagnostics.
+            let err = err.to_compile_error();
+            return quote! {
+                #input_struct
+                #err
+            }
+            .into();
+        }
+    };
 
-pub fn anno_component(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    let input_struct = parse_macro_input!(item as ItemStruct);
     let struct_ident = &input_struct.ident;
+    let struct_vis = &input_struct.vis;
     let struct_name_str = struct_ident.to_string();
 
+    // --- Generate the Typed Bean Key ---
+    // Every other reference to the bean name goes through the key, so it is spelled once.
+    let bean_key = quote! {
+        impl ::summer_core::Bean for #struct_ident {
+            const BEAN_NAME: &'static str = #struct_name_str;
+        }
+
+        impl #struct_ident {
+            /// Typed key of this component's bean, e.g. for `IocContainer::get`.
+            #struct_vis const BEAN: ::summer_core::BeanKey<#struct_ident> = ::summer_core::BeanKey::new();
+        }
+    };
+
     // --- Generate Constructor Wrapper ---
+    // `__summer_construct` and `__SUMMER_DEPENDENCIES` resolve to the inherent items emitted by
+    // `#[constructor]` when one exists, and otherwise to the `FieldInjection` impl generated for
+    // `#[autowired]` fields or the blanket `DefaultConstructor`/`NoDeclaredDependencies` impls.
+    let construction = if field_injection.is_empty() {
+        quote! { ::summer_core::__private::DefaultConstructor }
+    } else {
+        quote! { ::summer_core::__private::FieldInjection }
+    };
+    let declared_dependencies = if field_injection.is_empty() {
+        quote! { ::summer_core::__private::NoDeclaredDependencies }
+    } else {
+        quote! { ::summer_core::__private::FieldInjection }
+    };
     let constructor_wrapper = quote! {
-        |container: ::std::sync::Arc<dyn ::summer_core::BeanProvider>| -> Result<::std::sync::Arc<dyn ::std::any::Any + Send + Sync>, ::summer_core::ConstructorError> {
-            struct _RequiresDefault where #struct_ident: Default;
+        |provider: ::summer_core::BeanProviderRef| -> ::summer_core::BeanConstructorResult {
+            #[allow(unused_imports)]
+            use #construction as _;
 
-            let instance = #struct_ident::default();
+            let instance: #struct_ident = #struct_ident::__summer_construct(&provider)?;
             Ok(::std::sync::Arc::new(instance))
         }
     };
 
+    // --- Generate Provided Type Casters ---
+    // The `let view: Arc<#provided> = concrete;` coercion doubles as a compile-time check
+    // that the struct actually implements the provided trait.
+    let provided_types = args.provides.iter().map(|provided| {
+        quote_spanned! {provided.span()=>
+            ::summer_core::ProvidedTypeMetadata {
+                type_id: || ::std::any::TypeId::of::<#provided>(),
+                type_name: || ::std::any::type_name::<#provided>(),
+                caster: |instance: ::summer_core::BeanInstance| -> ::std::option::Option<::summer_core::BeanInstance> {
+                    let concrete = instance.downcast::<#struct_ident>().ok()?;
+                    let view: ::std::sync::Arc<#provided> = concrete;
+                    ::std::option::Option::Some(::std::sync::Arc::new(view))
+                },
+            }
+        }
+    });
+
+    let is_primary = args.primary;
+    let lazy = args.lazy;
+    let qualifiers = &args.qualifiers;
+    let scope = args
+        .scope
+        .as_ref()
+        .map_or_else(|| "singleton".to_string(), LitStr::value);
+
+    // Casting the generic notifier to the callback type checks that the trait is implemented.
+    let bean_name_aware = if args.bean_name_aware {
+        quote! {
+            ::std::option::Option::Some(
+                ::summer_core::notify_bean_name::<#struct_ident> as ::summer_core::BeanNameAwareCallback
+            )
+        }
+    } else {
+        quote! { ::std::option::Option::None }
+    };
+    let container_aware = if args.container_aware {
+        quote! {
+            ::std::option::Option::Some(
+                ::summer_core::notify_container::<#struct_ident> as ::summer_core::ContainerAwareCallback
+            )
+        }
+    } else {
+        quote! { ::std::option::Option::None }
+    };
+
     // Generate the code to submit metadata to inventory
     let generated_inventory_submission = quote! {
         ::summer_core::inventory::submit! {
             ::summer_core::BeanDefinitionMetadata {
-                bean_name: #struct_name_str,
+                bean_name: ::summer_core::BeanKey::name(#struct_ident::BEAN),
                 bean_type_id: || ::std::any::TypeId::of::<#struct_ident>(),
+                bean_type_name: || ::std::any::type_name::<#struct_ident>(),
                 constructor: #constructor_wrapper,
+                async_constructor: {
+                    #[allow(unused_imports)]
+                    use ::summer_core::__private::NoAsyncConstructor as _;
+                    #struct_ident::__SUMMER_ASYNC_CONSTRUCTOR
+                },
+                provides: &[#(#provided_types),*],
+                is_primary: #is_primary,
+                qualifiers: &[#(#qualifiers),*],
+                scope: #scope,
+                post_construct: {
+                    #[allow(unused_imports)]
+                    use ::summer_core::__private::NoLifecycleCallbacks as _;
+                    #struct_ident::__SUMMER_POST_CONSTRUCT
+                },
+                lazy: #lazy,
+                order: #order,
+                bean_name_aware: #bean_name_aware,
+                container_aware: #container_aware,
+                conditions: &[#(#conditions),*],
+                dependencies: {
+                    #[allow(unused_imports)]
+                    use #declared_dependencies as _;
+                    #struct_ident::__SUMMER_DEPENDENCIES
+                },
+                pre_destroy: {
+                    #[allow(unused_imports)]
+                    use ::summer_core::__private::NoLifecycleCallbacks as _;
+                    #struct_ident::__SUMMER_PRE_DESTROY
+                },
             }
         }
     };
 
-    // Add `#[derive(Default)]` if it doesn't exist (simplistic approach for now)
-    let mut final_struct = input_struct.clone();
-    let mut has_derive_default = false;
-    for attr in &input_struct.attrs {
-        if attr.path().is_ident("derive") {
-            let result = attr.parse_nested_meta(|meta| {
-                if meta.path.is_ident("Default") {
-                    has_derive_default = true;
-                    return Ok(());
+    let output = quote! {
+        #input_struct
+        #bean_key
+        #field_injection
+        #generated_inventory_submission
+    };
+
+    output.into()
+}
+
+/// Implements `FieldInjection` for the struct: `__summer_construct` resolves every
+/// `#[autowired]` field from the `BeanProvider` and fills the remaining fields with
+/// `Default::default()`.
+///
+/// Returns an empty token stream when the struct has no `#[autowired]` fields.
+fn generate_field_injection(
+    input_struct: &mut ItemStruct,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let mut dependencies = Vec::new();
+    let mut field_inits = Vec::new();
+    let mut errors: Option<syn::Error> = None;
+
+    for (index, field) in input_struct.fields.iter_mut().enumerate() {
+        let attr_count = field.attrs.len();
+        field
+            .attrs
+            .retain(|attr| !attr.path().is_ident("autowired"));
+        let autowired = field.attrs.len() != attr_count;
+
+        let ty = &field.ty;
+        let value = match (autowired, take_qualifier(&mut field.attrs)) {
+            (true, Ok(qualifier)) => match InjectedDependency::parse(ty, qualifier) {
+                Ok(dependency) => {
+                    let expr = dependency.resolve_expr();
+                    dependencies.push(dependency);
+                    expr
+                }
+                Err(err) => {
+                    push_error(&mut errors, err);
+                    continue;
                 }
-                Ok(())
-            });
-            if result.is_err() { /* Handle error */ }
-            if has_derive_default {
-                break;
+            },
+            (false, Ok(None)) => {
+                quote_spanned! {ty.span()=> ::std::default::Default::default() }
+            }
+            (false, Ok(Some(qualifier))) => {
+                push_error(
+                    &mut errors,
+                    syn::Error::new_spanned(qualifier, "#[qualifier] requires #[autowired]"),
+                );
+                continue;
+            }
+            (_, Err(err)) => {
+                push_error(&mut errors, err);
+                continue;
+            }
+        };
+
+        field_inits.push(match &field.ident {
+            Some(ident) => quote! { #ident: #value },
+            None => {
+                let index = syn::Index::from(index);
+                quote! { #index: #value }
+            }
+        });
+    }
+
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+    if dependencies.is_empty() {
+        return Ok(proc_macro2::TokenStream::new());
+    }
+
+    let struct_ident = &input_struct.ident;
+    let (impl_generics, ty_generics, where_clause) = input_struct.generics.split_for_impl();
+    let construct = match &input_struct.fields {
+        Fields::Unit => quote! { Self },
+        _ => quote! { Self { #(#field_inits),* } },
+    };
+    let dependencies = dependencies.iter().map(InjectedDependency::metadata);
+    // A `#[constructor]` impl on the same struct is rejected with a readable message instead
+    // of clashing with the items generated here.
+    let conflict = LitStr::new(
+        &format!(
+            "`{}` cannot combine #[autowired] fields with a #[constructor] impl; \
+             inject every dependency as a parameter of `new` or as an #[autowired] field",
+            struct_ident
+        ),
+        struct_ident.span(),
+    );
+
+    Ok(quote! {
+        impl #impl_generics ::summer_core::__private::FieldInjection for #struct_ident #ty_generics #where_clause {
+            const __SUMMER_DEPENDENCIES: &'static [::summer_core::DependencyMetadata] =
+                &[#(#dependencies),*];
+
+            fn __summer_construct(
+                provider: &::summer_core::BeanProviderRef,
+            ) -> ::std::result::Result<Self, ::summer_core::ConstructorError> {
+                ::std::result::Result::Ok(#construct)
             }
         }
+
+        const _: () = {
+            #[allow(unused_imports)]
+            use ::summer_core::__private::NoConstructorImpl as _;
+            ::std::assert!(!#struct_ident::__SUMMER_HAS_CONSTRUCTOR, #conflict);
+        };
+    })
+}
+
+fn push_error(errors: &mut Option<syn::Error>, err: syn::Error) {
+    match errors {
+        Some(errors) => errors.combine(err),
+        None => *errors = Some(err),
     }
-    if !has_derive_default {
-        let default_derive_attr: Attribute = parse_quote!(#[derive(Default)]);
-        final_struct.attrs.push(default_derive_attr);
+}
+
+pub fn anno_constructor(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut impl_item = parse_macro_input!(item as ItemImpl);
+
+    // `#[qualifier]` on `new` parameters is stripped by the wrapper generation, so the impl
+    // block must only be emitted afterwards.
+    match generate_constructor_wrapper(&mut impl_item) {
+        Ok(wrapper) => quote! {
+            #impl_item
+            #wrapper
+        }
+        .into(),
+        Err(err) => {
+            let err = err.to_compile_error();
+            quote! {
+                #impl_item
+                #err
+            }
+            .into()
+        }
     }
+}
 
-    // Combine the potentially modified struct definition with the generated code
-    let output = quote! {
-        #final_struct
-        #generated_inventory_submission
+/// Generates an inherent `__summer_construct` function that resolves every parameter of
+/// the impl block's `new` function from the `BeanProvider` and forwards them to `new`.
+///
+/// For an `async fn new` the resolved parameters are forwarded from an inherent
+/// `__SUMMER_ASYNC_CONSTRUCTOR` instead, and `__summer_construct` only reports that the bean
+/// has to be built asynchronously (the container never calls it for such beans).
+fn generate_constructor_wrapper(impl_item: &mut ItemImpl) -> syn::Result<proc_macro2::TokenStream> {
+    let self_ty_span = impl_item.self_ty.span();
+    let new_fn = impl_item
+        .items
+        .iter_mut()
+        .find_map(|item| match item {
+            ImplItem::Fn(method) if method.sig.ident == "new" => Some(method),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            syn::Error::new(
+                self_ty_span,
+                "#[constructor] requires the impl block to define an associated `new` function",
+            )
+        })?;
+
+    let mut dependencies = Vec::new();
+    for input in new_fn.sig.inputs.iter_mut() {
+        match input {
+            FnArg::Typed(pat_type) => {
+                let qualifier = take_qualifier(&mut pat_type.attrs)?;
+                dependencies.push(InjectedDependency::parse(&pat_type.ty, qualifier)?);
+            }
+            FnArg::Receiver(receiver) => {
+                return Err(syn::Error::new_spanned(
+                    receiver,
+                    "a #[constructor] `new` function cannot take `self`",
+                ))
+            }
+        }
+    }
+
+    let dep_fetches = dependencies.iter().map(InjectedDependency::resolve_expr);
+    let dependencies_const = dependencies_const(&dependencies);
+    // Lets `#[component]` reject structs that also have `#[autowired]` fields.
+    let marker = quote! {
+        #[doc(hidden)]
+        pub const __SUMMER_HAS_CONSTRUCTOR: bool = true;
     };
+    let self_ty = &impl_item.self_ty;
+    let (impl_generics, _, where_clause) = impl_item.generics.split_for_impl();
 
-    output.into()
+    if new_fn.sig.asyncness.is_none() {
+        return Ok(quote! {
+            impl #impl_generics #self_ty #where_clause {
+                #[doc(hidden)]
+                pub fn __summer_construct(
+                    provider: &::summer_core::BeanProviderRef,
+                ) -> ::std::result::Result<Self, ::summer_core::ConstructorError> {
+                    ::std::result::Result::Ok(Self::new(#(#dep_fetches),*))
+                }
+
+                #dependencies_const
+                #marker
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics #self_ty #where_clause {
+            #[doc(hidden)]
+            pub fn __summer_construct(
+                _provider: &::summer_core::BeanProviderRef,
+            ) -> ::std::result::Result<Self, ::summer_core::ConstructorError> {
+                ::std::result::Result::Err(::summer_core::ConstructorError::BaseMsgError(
+                    ::std::format!(
+                        "`{}` has an async constructor and must be built asynchronously",
+                        ::std::any::type_name::<Self>()
+                    ),
+                ))
+            }
+
+            #[doc(hidden)]
+            pub const __SUMMER_ASYNC_CONSTRUCTOR: ::std::option::Option<::summer_core::AsyncBeanConstructor> =
+                ::std::option::Option::Some(
+                    |provider: ::summer_core::BeanProviderRef| -> ::summer_core::BeanConstructorFuture {
+                        ::std::boxed::Box::pin(async move {
+                            let provider = &provider;
+                            let instance = Self::new(#(#dep_fetches),*).await;
+                            ::std::result::Result::Ok(
+                                ::std::sync::Arc::new(instance) as ::summer_core::BeanInstance
+                            )
+                        })
+                    },
+                );
+
+            #dependencies_const
+            #marker
+        }
+    })
 }
 
+/// Removes the condition attributes (`#[conditional_on_property(..)]`,
+/// `#[conditional_on_missing_bean(Type)]`, `#[conditional_on_feature("name")]` and
+/// `#[profile("name", ..)]`) from a component struct, returning their `ConditionMetadata`.
+fn take_conditions(attrs: &mut Vec<Attribute>) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    const CONDITIONS: [&str; 4] = [
+        "conditional_on_property",
+        "conditional_on_missing_bean",
+        "conditional_on_feature",
+        "profile",
+    ];
+    let (condition_attrs, kept): (Vec<Attribute>, Vec<Attribute>) = std::mem::take(attrs)
+        .into_iter()
+        .partition(|attr| CONDITIONS.iter().any(|name| attr.path().is_ident(name)));
+    *attrs = kept;
 
-// --- Helper function (Example of how parsing might look - NOT USED YET) ---
-fn _find_new_and_generate_wrapper(
-    struct_item: &ItemStruct,
-    impl_item: &ItemImpl,
-) -> proc_macro2::TokenStream {
-    let struct_ident = &struct_item.ident;
-    let mut new_fn = None;
-
-    // Check if the impl block is for our struct
-    if let Type::Path(type_path) = &*impl_item.self_ty {
-        if type_path.path.is_ident(struct_ident) {
-            // Find the 'new' function
-            for item in &impl_item.items {
-                if let ImplItem::Fn(method) = item {
-                    if method.sig.ident == "new" {
-                        new_fn = Some(method);
-                        break;
+    condition_attrs
+        .iter()
+        .map(|attr| {
+            if attr.path().is_ident("conditional_on_property") {
+                property_condition(attr)
+            } else if attr.path().is_ident("conditional_on_missing_bean") {
+                let ty: Type = attr.parse_args()?;
+                Ok(quote_spanned! {ty.span()=>
+                    ::summer_core::ConditionMetadata::OnMissingBean {
+                        type_id: || ::std::any::TypeId::of::<#ty>(),
+                        type_name: || ::std::any::type_name::<#ty>(),
                     }
+                })
+            } else if attr.path().is_ident("conditional_on_feature") {
+                // `cfg!` expands in the crate declaring the component, so it sees its features.
+                let feature: LitStr = attr.parse_args()?;
+                Ok(quote! {
+                    ::summer_core::ConditionMetadata::OnFeature {
+                        feature: #feature,
+                        enabled: ::std::cfg!(feature = #feature),
+                    }
+                })
+            } else {
+                let profiles =
+                    attr.parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)?;
+                if profiles.is_empty() {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "#[profile] requires at least one profile name",
+                    ));
                 }
+                let profiles = profiles.iter();
+                Ok(quote! { ::summer_core::ConditionMetadata::Profile(&[#(#profiles),*]) })
             }
+        })
+        .collect()
+}
+
+/// Parses `#[conditional_on_property(name = "..", having_value = "..", match_if_missing)]`.
+fn property_condition(attr: &Attribute) -> syn::Result<proc_macro2::TokenStream> {
+    let mut name: Option<LitStr> = None;
+    let mut having_value: Option<LitStr> = None;
+    let mut match_if_missing = false;
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("name") {
+            name = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("having_value") {
+            having_value = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("match_if_missing") {
+            match_if_missing = if meta.input.peek(Token![=]) {
+                meta.value()?.parse::<LitBool>()?.value
+            } else {
+                true
+            };
+        } else {
+            return Err(meta.error("unsupported #[conditional_on_property] argument"));
         }
+        Ok(())
+    })?;
+
+    let name = name.ok_or_else(|| {
+        syn::Error::new_spanned(attr, "#[conditional_on_property] requires `name = \"...\"`")
+    })?;
+    let having_value = match having_value {
+        Some(value) => quote! { ::std::option::Option::Some(#value) },
+        None => quote! { ::std::option::Option::None },
+    };
+    Ok(quote! {
+        ::summer_core::ConditionMetadata::OnProperty {
+            name: #name,
+            having_value: #having_value,
+            match_if_missing: #match_if_missing,
+        }
+    })
+}
+
+/// Removes `#[order(n)]` from a component struct, returning `n`.
+fn take_order(attrs: &mut Vec<Attribute>) -> syn::Result<Option<syn::Expr>> {
+    let Some(index) = attrs.iter().position(|attr| attr.path().is_ident("order")) else {
+        return Ok(None);
+    };
+    let attr = attrs.remove(index);
+    if let Some(duplicate) = attrs.iter().find(|attr| attr.path().is_ident("order")) {
+        return Err(syn::Error::new_spanned(
+            duplicate,
+            "only one #[order] is allowed per component",
+        ));
     }
+    attr.parse_args::<syn::Expr>().map(Some)
+}
 
-    if let Some(func) = new_fn {
-        let mut dep_fetches = Vec::new();
-        let mut dep_names = Vec::new();
-        for input in func.sig.inputs.iter() {
-            if let FnArg::Typed(PatType { pat, ty, .. }) = input {
-                let dep_name = &**pat;
-                let dep_type = &**ty;
-                dep_fetches.push(quote_spanned! {ty.span()=>
-                    let #dep_name = container.get_bean_by_typeid(::std::any::TypeId::of::<#dep_type>())?
-                        .downcast::<#dep_type>()
-                        .map_err(|_| Box::new(::summer_core::ConstructorError::from(format!("Type mismatch for dependency of type {}", stringify!(#dep_type)))) as Box<dyn std::error::Error + Send + Sync>)?;
-                });
-                dep_names.push(dep_name.to_token_stream());
-            } else if let FnArg::Receiver(_) = input {
-                // Skip `self` if present
-            } else {
-                return quote_spanned! {input.span()=> compile_error!("Unsupported argument type in constructor"); };
+/// The bean selected by `#[qualifier(..)]`.
+enum Qualifier {
+    /// `#[qualifier("fast")]`: a declared qualifier or a bean name.
+    Name(LitStr),
+    /// `#[qualifier(RedisCache::BEAN)]`: the bean of a component, checked at compile time.
+    Key(ExprPath),
+}
+
+impl Qualifier {
+    /// The qualifier as a `&'static str` expression in a constructor wrapper. For a key this
+    /// also checks that the keyed component can be injected as `Arc<inner>`.
+    fn resolve_expr(&self, inner: &Type) -> proc_macro2::TokenStream {
+        match self {
+            Qualifier::Name(name) => quote! { #name },
+            Qualifier::Key(key) => quote_spanned! {key.span()=>
+                ::summer_core::__private::qualifier_key::<#inner, _>(#key, |bean| bean)
+            },
+        }
+    }
+
+    /// The qualifier as a constant expression, for `DependencyMetadata`.
+    fn const_expr(&self) -> proc_macro2::TokenStream {
+        match self {
+            Qualifier::Name(name) => quote! { #name },
+            Qualifier::Key(key) => quote! { ::summer_core::BeanKey::name(#key) },
+        }
+    }
+}
+
+impl ToTokens for Qualifier {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            Qualifier::Name(name) => name.to_tokens(tokens),
+            Qualifier::Key(key) => key.to_tokens(tokens),
+        }
+    }
+}
+
+/// Removes `#[qualifier("name")]` or `#[qualifier(Component::BEAN)]` from `attrs` and returns
+/// it, if present.
+fn take_qualifier(attrs: &mut Vec<Attribute>) -> syn::Result<Option<Qualifier>> {
+    let Some(index) = attrs
+        .iter()
+        .position(|attr| attr.path().is_ident("qualifier"))
+    else {
+        return Ok(None);
+    };
+    let attr = attrs.remove(index);
+    if let Some(duplicate) = attrs.iter().find(|attr| attr.path().is_ident("qualifier")) {
+        return Err(syn::Error::new_spanned(
+            duplicate,
+            "only one #[qualifier] is allowed per injection point",
+        ));
+    }
+    match attr.parse_args::<LitStr>() {
+        Ok(name) => Ok(Some(Qualifier::Name(name))),
+        Err(_) => attr
+            .parse_args::<ExprPath>()
+            .map(|key| Some(Qualifier::Key(key)))
+            .map_err(|_| {
+                syn::Error::new_spanned(
+                    &attr,
+                    "expected a bean name or a component key, e.g. #[qualifier(\"fast\")] or #[qualifier(RedisCache::BEAN)]",
+                )
+            }),
+    }
+}
+
+/// How an injection point receives beans of its type `T`.
+#[derive(Clone, Copy, PartialEq)]
+enum InjectionKind {
+    /// `Arc<T>`: exactly one bean must match.
+    Required,
+    /// `Option<Arc<T>>`: `None` when no bean matches.
+    Optional,
+    /// `Vec<Arc<T>>`: every matching bean, in bean order.
+    All,
+    /// `HashMap<String, Arc<T>>`: every matching bean, keyed by bean name.
+    ByName,
+    /// `Lazy<T>`: resolved on first use, then kept.
+    Lazy,
+    /// `ObjectProvider<T>`: resolved on every use.
+    Provider,
+}
+
+/// An injection point: a `#[constructor]` parameter or an `#[autowired]` field.
+///
+/// `T` may be a trait object (`Arc<dyn Trait>`), which is resolved through the provided type
+/// index. With a `qualifier`, only beans declaring that qualifier (or named after it) are
+/// candidates.
+struct InjectedDependency {
+    ty: Type,
+    /// The bean type `T` (e.g. the `T` in `Arc<T>` or `Lazy<T>`).
+    inner: Type,
+    kind: InjectionKind,
+    qualifier: Option<Qualifier>,
+}
+
+impl InjectedDependency {
+    fn parse(ty: &Type, qualifier: Option<Qualifier>) -> syn::Result<Self> {
+        fn arc_of(wrapped: Option<&Type>) -> Option<&Type> {
+            wrapped.and_then(|t| generic_inner_type(t, "Arc"))
+        }
+        let (inner, kind) = if let Some(inner) = generic_inner_type(ty, "Arc") {
+            (inner, InjectionKind::Required)
+        } else if let Some(inner) = arc_of(generic_inner_type(ty, "Option")) {
+            (inner, InjectionKind::Optional)
+        } else if let Some(inner) = arc_of(generic_inner_type(ty, "Vec")) {
+            (inner, InjectionKind::All)
+        } else if let Some(inner) = arc_of(string_map_value_type(ty)) {
+            (inner, InjectionKind::ByName)
+        } else if let Some(inner) = generic_inner_type(ty, "Lazy") {
+            (inner, InjectionKind::Lazy)
+        } else if let Some(inner) = generic_inner_type(ty, "ObjectProvider") {
+            (inner, InjectionKind::Provider)
+        } else {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "injected dependencies must be of type `Arc<T>`, `Option<Arc<T>>`, \
+                 `Vec<Arc<T>>`, `HashMap<String, Arc<T>>`, `Lazy<T>` or `ObjectProvider<T>`",
+            ));
+        };
+
+        if let (Some(qualifier), InjectionKind::All | InjectionKind::ByName) = (&qualifier, kind) {
+            return Err(syn::Error::new_spanned(
+                qualifier,
+                "#[qualifier] cannot be used when injecting every bean of a type",
+            ));
+        }
+        Ok(InjectedDependency {
+            ty: ty.clone(),
+            inner: inner.clone(),
+            kind,
+            qualifier,
+        })
+    }
+
+    /// Expression resolving the dependency from `provider`, propagating errors with `?`.
+    fn resolve_expr(&self) -> proc_macro2::TokenStream {
+        let inner = &self.inner;
+        let dyn_suffix = if is_trait_object(inner) { "_dyn" } else { "" };
+        let resolver = |name: &str| {
+            proc_macro2::Ident::new(
+                &format!("{name}{dyn_suffix}"),
+                proc_macro2::Span::call_site(),
+            )
+        };
+
+        let qualifier = match &self.qualifier {
+            Some(q) => {
+                let q = q.resolve_expr(inner);
+                quote! { ::std::option::Option::Some(#q) }
+            }
+            None => quote! { ::std::option::Option::None },
+        };
+        // Deferred handles only capture the provider; nothing is resolved yet.
+        let new = resolver("new");
+        match self.kind {
+            InjectionKind::Lazy => {
+                return quote_spanned! {self.ty.span()=>
+                    ::summer_core::Lazy::<#inner>::#new(provider, #qualifier)
+                }
+            }
+            InjectionKind::Provider => {
+                return quote_spanned! {self.ty.span()=>
+                    ::summer_core::ObjectProvider::<#inner>::#new(provider, #qualifier)
+                }
+            }
+            _ => {}
+        }
+
+        let resolve = match (self.kind, &self.qualifier) {
+            (InjectionKind::All, _) => {
+                let resolve_beans = resolver("resolve_beans");
+                quote! { ::summer_core::#resolve_beans::<#inner>(&**provider) }
+            }
+            (InjectionKind::ByName, _) => {
+                let resolve_bean_map = resolver("resolve_bean_map");
+                quote! { ::summer_core::#resolve_bean_map::<#inner>(&**provider) }
+            }
+            (_, None) => {
+                let resolve_bean = resolver("resolve_bean");
+                quote! { ::summer_core::#resolve_bean::<#inner>(&**provider) }
             }
+            (_, Some(q)) => {
+                let q = q.resolve_expr(inner);
+                let resolve_qualified_bean = resolver("resolve_qualified_bean");
+                quote! { ::summer_core::#resolve_qualified_bean::<#inner>(&**provider, #q) }
+            }
+        };
+
+        if self.kind == InjectionKind::Optional {
+            quote_spanned! {self.ty.span()=> ::summer_core::optional_bean(#resolve)? }
+        } else {
+            quote_spanned! {self.ty.span()=> #resolve? }
         }
+    }
 
+    /// `DependencyMetadata` describing this injection point for the dependency graph.
+    fn metadata(&self) -> proc_macro2::TokenStream {
+        let inner = &self.inner;
+        let optional = self.kind == InjectionKind::Optional;
+        let collection = matches!(self.kind, InjectionKind::All | InjectionKind::ByName);
+        let lazy = matches!(self.kind, InjectionKind::Lazy | InjectionKind::Provider);
+        let qualifier = match &self.qualifier {
+            Some(q) => {
+                let q = q.const_expr();
+                quote! { ::std::option::Option::Some(#q) }
+            }
+            None => quote! { ::std::option::Option::None },
+        };
         quote! {
-            |container: ::std::sync::Arc<dyn ::summer_core::BeanProvider>| -> Result<::std::sync::Arc<dyn ::std::any::Any + Send + Sync>, ::summer_core::ConstructorError> {
-                #(#dep_fetches)*
-                let instance = #struct_ident::new(#(#dep_names),*);
-                Ok(::std::sync::Arc::new(instance))
+            ::summer_core::DependencyMetadata {
+                type_id: || ::std::any::TypeId::of::<#inner>(),
+                type_name: || ::std::any::type_name::<#inner>(),
+                qualifier: #qualifier,
+                optional: #optional,
+                collection: #collection,
+                lazy: #lazy,
+            }
+        }
+    }
+}
+
+/// The inherent `__SUMMER_DEPENDENCIES` const picked up by `#[component]`.
+fn dependencies_const(dependencies: &[InjectedDependency]) -> proc_macro2::TokenStream {
+    let metadata = dependencies.iter().map(InjectedDependency::metadata);
+    quote! {
+        #[doc(hidden)]
+        pub const __SUMMER_DEPENDENCIES: &'static [::summer_core::DependencyMetadata] =
+            &[#(#metadata),*];
+    }
+}
+
+/// Returns `true` for `dyn Trait` types (optionally parenthesized).
+fn is_trait_object(ty: &Type) -> bool {
+    match ty {
+        Type::TraitObject(_) => true,
+        Type::Paren(paren) => is_trait_object(&paren.elem),
+        _ => false,
+    }
+}
+
+/// Returns `T` if `ty` is written as `Wrapper<T>` (with or without a path prefix).
+fn generic_inner_type<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+/// Returns `V` if `ty` is written as `HashMap<String, V>`.
+fn string_map_value_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "HashMap" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match (args.args.first()?, args.args.iter().nth(1)?) {
+        (GenericArgument::Type(Type::Path(key)), GenericArgument::Type(value))
+            if args.args.len() == 2 && key.path.is_ident("String") =>
+        {
+            Some(value)
+        }
+        _ => None,
+    }
+}
+
+/// Which lifecycle phase a method attribute binds to.
+#[derive(Clone, Copy)]
+pub enum LifecyclePhase {
+    PostConstruct,
+    PreDestroy,
+}
+
+impl LifecyclePhase {
+    fn attribute(self) -> &'static str {
+        match self {
+            LifecyclePhase::PostConstruct => "#[post_construct]",
+            LifecyclePhase::PreDestroy => "#[pre_destroy]",
+        }
+    }
+
+    fn const_ident(self) -> proc_macro2::Ident {
+        let name = match self {
+            LifecyclePhase::PostConstruct => "__SUMMER_POST_CONSTRUCT",
+            LifecyclePhase::PreDestroy => "__SUMMER_PRE_DESTROY",
+        };
+        proc_macro2::Ident::new(name, proc_macro2::Span::call_site())
+    }
+}
+
+pub fn anno_lifecycle(phase: LifecyclePhase, attr: TokenStream, item: TokenStream) -> TokenStream {
+    let method = parse_macro_input!(item as ImplItemFn);
+    if !attr.is_empty() {
+        let err = syn::Error::new(
+            proc_macro2::TokenStream::from(attr).span(),
+            format!("{} does not take arguments", phase.attribute()),
+        )
+        .to_compile_error();
+        return quote! {
+            #method
+            #err
+        }
+        .into();
+    }
+
+    match generate_lifecycle_callback(phase, &method) {
+        Ok(callback) => quote! {
+            #method
+            #callback
+        }
+        .into(),
+        Err(err) => {
+            let err = err.to_compile_error();
+            quote! {
+                #method
+                #err
             }
+            .into()
+        }
+    }
+}
+
+/// Generates the inherent associated const that `#[component]` picks up as the bean's
+/// lifecycle callback, shadowing the `NoLifecycleCallbacks` fallback.
+fn generate_lifecycle_callback(
+    phase: LifecyclePhase,
+    method: &ImplItemFn,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let sig = &method.sig;
+    let takes_ref_self = matches!(
+        sig.inputs.first(),
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_some() && receiver.mutability.is_none()
+    );
+    if !takes_ref_self || sig.inputs.len() != 1 || !sig.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            sig,
+            format!(
+                "{} methods must take `&self` as their only parameter",
+                phase.attribute()
+            ),
+        ));
+    }
+
+    let method_ident = &sig.ident;
+    let const_ident = phase.const_ident();
+    let callback = if sig.asyncness.is_some() {
+        quote! {
+            ::summer_core::LifecycleCallback::Async(
+                |instance: ::summer_core::BeanInstance| -> ::summer_core::LifecycleFuture {
+                    ::std::boxed::Box::pin(async move {
+                        let bean = instance
+                            .downcast::<Self>()
+                            .map_err(|_| ::summer_core::ConstructorError::TypeMismatch(::summer_core::TypeInfo::of::<Self>()))?;
+                        ::summer_core::IntoLifecycleResult::into_lifecycle_result(bean.#method_ident().await)
+                    })
+                },
+            )
         }
     } else {
         quote! {
-            |container: ::std::sync::Arc<dyn ::summer_core::BeanProvider>| -> Result<::std::sync::Arc<dyn ::std::any::Any + Send + Sync>, ::summer_core::ConstructorError> {
-                struct _RequiresDefault where #struct_ident: Default;
-                let instance = #struct_ident::default();
-                Ok(::std::sync::Arc::new(instance))
+            ::summer_core::LifecycleCallback::Sync(
+                |instance: &::summer_core::BeanInstance| -> ::std::result::Result<(), ::summer_core::ConstructorError> {
+                    let bean = instance
+                        .downcast_ref::<Self>()
+                        .ok_or(::summer_core::ConstructorError::TypeMismatch(::summer_core::TypeInfo::of::<Self>()))?;
+                    ::summer_core::IntoLifecycleResult::into_lifecycle_result(bean.#method_ident())
+                },
+            )
+        }
+    };
+
+    Ok(quote! {
+        #[doc(hidden)]
+        pub const #const_ident: ::std::option::Option<::summer_core::LifecycleCallback> =
+            ::std::option::Option::Some(#callback);
+    })
+}
+
+/// `#[event_listener]` on an impl block: registers every method marked `#[event_listener]`
+/// as a listener for the event type of its parameter.
+pub fn anno_event_listener(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut impl_item = match syn::parse::<ItemImpl>(item.clone()) {
+        Ok(impl_item) => impl_item,
+        Err(_) => {
+            // Left on a method when the enclosing impl block is not annotated.
+            let method = parse_macro_input!(item as ImplItemFn);
+            let err = syn::Error::new_spanned(
+                &method.sig,
+                "the impl block of an #[event_listener] method must be annotated with #[event_listener] as well",
+            )
+            .to_compile_error();
+            return quote! {
+                #method
+                #err
+            }
+            .into();
+        }
+    };
+    if !attr.is_empty() {
+        let err = syn::Error::new(
+            proc_macro2::TokenStream::from(attr).span(),
+            "#[event_listener] does not take arguments",
+        )
+        .to_compile_error();
+        return quote! {
+            #impl_item
+            #err
+        }
+        .into();
+    }
+
+    match generate_event_listeners(&mut impl_item) {
+        Ok(listeners) => quote! {
+            #impl_item
+            #(#listeners)*
+        }
+        .into(),
+        Err(err) => {
+            let err = err.to_compile_error();
+            quote! {
+                #impl_item
+                #err
             }
+            .into()
+        }
+    }
+}
+
+/// Strips the `#[event_listener]` markers from the impl block's methods and generates one
+/// `EventListenerMetadata` submission per marked method.
+fn generate_event_listeners(
+    impl_item: &mut ItemImpl,
+) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    if !impl_item.generics.params.is_empty() || impl_item.trait_.is_some() {
+        return Err(syn::Error::new_spanned(
+            &impl_item.self_ty,
+            "#[event_listener] must be placed on an inherent impl block of a non-generic component",
+        ));
+    }
+    let self_ty = impl_item.self_ty.clone();
+
+    let mut listeners = Vec::new();
+    let mut errors = None;
+    for item in &mut impl_item.items {
+        let ImplItem::Fn(method) = item else { continue };
+        let before = method.attrs.len();
+        method
+            .attrs
+            .retain(|attr| !attr.path().is_ident("event_listener"));
+        if method.attrs.len() == before {
+            continue;
+        }
+        match event_listener_submission(&self_ty, method) {
+            Ok(listener) => listeners.push(listener),
+            Err(err) => push_error(&mut errors, err),
         }
     }
-}
\ No newline at end of file
+    if let Some(err) = errors {
+        return Err(err);
+    }
+    if listeners.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &impl_item.self_ty,
+            "#[event_listener] impl blocks must mark at least one method with #[event_listener]",
+        ));
+    }
+    Ok(listeners)
+}
+
+fn event_listener_submission(
+    self_ty: &Type,
+    method: &ImplItemFn,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let sig = &method.sig;
+    let takes_ref_self = matches!(
+        sig.inputs.first(),
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_some() && receiver.mutability.is_none()
+    );
+    let event_ty = match sig.inputs.iter().nth(1) {
+        Some(FnArg::Typed(arg)) => match &*arg.ty {
+            Type::Reference(reference) if reference.mutability.is_none() => {
+                Some((*reference.elem).clone())
+            }
+            _ => None,
+        },
+        _ => None,
+    };
+    let event_ty = match event_ty {
+        Some(event_ty) if takes_ref_self && sig.inputs.len() == 2 && sig.generics.params.is_empty() => {
+            event_ty
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                sig,
+                "#[event_listener] methods must take `&self` and a reference to the event, e.g. `fn on_order(&self, event: &OrderPlaced)`",
+            ))
+        }
+    };
+
+    let method_ident = &sig.ident;
+    let method_name = method_ident.to_string();
+    let callback = if sig.asyncness.is_some() {
+        quote! {
+            ::summer_core::EventListenerCallback::Async(
+                |bean: ::summer_core::BeanInstance, event: ::summer_core::EventInstance| -> ::summer_core::EventFuture {
+                    ::std::boxed::Box::pin(async move {
+                        let bean = bean
+                            .downcast::<#self_ty>()
+                            .map_err(|_| ::summer_core::ConstructorError::TypeMismatch(::summer_core::TypeInfo::of::<#self_ty>()))?;
+                        let event = event
+                            .downcast::<#event_ty>()
+                            .map_err(|_| ::summer_core::ConstructorError::TypeMismatch(::summer_core::TypeInfo::of::<#event_ty>()))?;
+                        ::summer_core::IntoLifecycleResult::into_lifecycle_result(bean.#method_ident(&event).await)
+                    })
+                },
+            )
+        }
+    } else {
+        quote! {
+            ::summer_core::EventListenerCallback::Sync(
+                |bean: &::summer_core::BeanInstance, event: &::summer_core::EventInstance| -> ::std::result::Result<(), ::summer_core::ConstructorError> {
+                    let bean = bean
+                        .downcast_ref::<#self_ty>()
+                        .ok_or(::summer_core::ConstructorError::TypeMismatch(::summer_core::TypeInfo::of::<#self_ty>()))?;
+                    let event = event
+                        .downcast_ref::<#event_ty>()
+                        .ok_or(::summer_core::ConstructorError::TypeMismatch(::summer_core::TypeInfo::of::<#event_ty>()))?;
+                    ::summer_core::IntoLifecycleResult::into_lifecycle_result(bean.#method_ident(event))
+                },
+            )
+        }
+    };
+
+    Ok(quote! {
+        ::summer_core::inventory::submit! {
+            ::summer_core::EventListenerMetadata {
+                bean_type_id: || ::std::any::TypeId::of::<#self_ty>(),
+                event_type_id: ::summer_core::__private::event_type_id::<#event_ty>,
+                event_type_name: || ::std::any::type_name::<#event_ty>(),
+                method_name: #method_name,
+                callback: #callback,
+            }
+        }
+    })
+}
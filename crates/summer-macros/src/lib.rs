@@ -1,13 +1,98 @@
 extern crate proc_macro;
 mod ioc;
 
+use crate::ioc::{
+    anno_component, anno_constructor, anno_event_listener, anno_lifecycle, LifecyclePhase,
+};
 use proc_macro::TokenStream;
-use crate::ioc::anno_component;
 
 /// Macro to mark a struct as a component managed by the IOC container.
+///
+/// Fields marked `#[autowired]` (of the same types as `#[constructor]` parameters) are injected
+/// from the container and all other fields use `Default`. Without `#[autowired]` fields or a
+/// companion `#[constructor]` impl the struct must implement `Default`. A struct cannot have
+/// both `#[autowired]` fields and a `#[constructor]` impl.
+///
+/// The struct gets a typed key, `MyService::BEAN`, for `IocContainer::get(MyService::BEAN)` and
+/// `#[qualifier(MyService::BEAN)]`.
+///
+/// `#[component(provides = dyn Trait)]` also registers the bean under `dyn Trait`, so it can be
+/// injected as `Arc<dyn Trait>` or fetched with `IocContainer::get_bean_dyn::<dyn Trait>()`.
+///
+/// When several beans share a type, `#[component(primary)]` marks the default candidate and
+/// `#[component(qualifier = "fast")]` lets injection points pick a bean with `#[qualifier("fast")]`.
+///
+/// `#[component(scope = "prototype")]` creates a new instance on every lookup; any other scope
+/// name refers to a custom scope registered with `IocContainer::register_scope`.
+///
+/// `Vec<Arc<T>>` and `HashMap<String, Arc<T>>` injection points receive every bean of type `T`
+/// (keyed by bean name for the map). A `#[order(n)]` attribute placed after `#[component]`
+/// positions the bean in such lists; lower values come first.
+///
+/// Singletons are created eagerly when the container initializes; `#[component(lazy)]` defers
+/// creation to the first lookup.
+///
+/// `#[component(bean_name_aware)]` and `#[component(container_aware)]` have the container call
+/// the struct's `BeanNameAware`/`ContainerAware` implementation right after construction.
+///
+/// Condition attributes placed after `#[component]` make registration conditional; the
+/// container evaluates them in `initialize` and reports skipped beans:
+/// `#[conditional_on_property(name = "cache.enabled", having_value = "true", match_if_missing)]`,
+/// `#[profile("dev", "!prod")]`, `#[conditional_on_feature("redis")]` and
+/// `#[conditional_on_missing_bean(dyn Cache)]` for fallbacks.
 #[proc_macro_attribute]
-pub fn component(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    anno_component(_attr, item)
+pub fn component(attr: TokenStream, item: TokenStream) -> TokenStream {
+    anno_component(attr, item)
 }
 
+/// Marks the impl block whose `new` function the container should use to build a component.
+///
+/// Every parameter of `new` must be an `Arc<T>`, `Option<Arc<T>>`, `Vec<Arc<T>>`,
+/// `HashMap<String, Arc<T>>`, `Lazy<T>` or `ObjectProvider<T>`; each one is resolved from the
+/// container, single beans optionally narrowed with `#[qualifier("name")]` or, checked at compile
+/// time, `#[qualifier(FastGateway::BEAN)]`. `Lazy<T>` and
+/// `ObjectProvider<T>` defer resolution until first use, which allows circular references.
+///
+/// `new` may be an `async fn`, e.g. to connect a client. `IocContainer::initialize_async` and
+/// `IocContainer::get_bean_async` await it; synchronous lookups of the component fail with
+/// `IocError::AsyncConstructorRequired`.
+#[proc_macro_attribute]
+pub fn constructor(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    anno_constructor(_attr, item)
+}
+
+/// Marks a `&self` method to run right after the component is constructed and injected.
+///
+/// The method may return `()` or `Result<(), E>` where `E` converts into
+/// `Box<dyn Error + Send + Sync>` (any error type, or a `String`), and may be `async`. A
+/// component with an async callback can only be created by `IocContainer::initialize_async`
+/// and `IocContainer::get_bean_async`; synchronous lookups fail.
+/// Use it inside an inherent impl block of a `#[component]` struct.
+#[proc_macro_attribute]
+pub fn post_construct(attr: TokenStream, item: TokenStream) -> TokenStream {
+    anno_lifecycle(LifecyclePhase::PostConstruct, attr, item)
+}
 
+/// Marks a `&self` method to run when the container is closed, in reverse creation order.
+///
+/// Accepts the same signatures as `#[post_construct]`. Only singleton beans are destroyed
+/// by the container, and async callbacks only by `IocContainer::close_async`.
+#[proc_macro_attribute]
+pub fn pre_destroy(attr: TokenStream, item: TokenStream) -> TokenStream {
+    anno_lifecycle(LifecyclePhase::PreDestroy, attr, item)
+}
+
+/// Registers methods of a component as application event listeners.
+///
+/// Place it on an inherent impl block of a `#[component]` struct and on each listener method
+/// in it. A listener takes `&self` and a reference to an `ApplicationEvent` type, may be
+/// `async`, and returns `()` or `Result<(), E>` like `#[post_construct]`. Listeners of a
+/// singleton are registered when it is created and receive events published with
+/// `IocContainer::publish_event`, including `ContextRefreshed`, `ContextClosing` and
+/// `BeanCreated`. Async listeners only receive events published asynchronously, e.g. with
+/// `IocContainer::publish_event_async` or by `initialize_async`; synchronous publishing skips
+/// them with a warning.
+#[proc_macro_attribute]
+pub fn event_listener(attr: TokenStream, item: TokenStream) -> TokenStream {
+    anno_event_listener(attr, item)
+}
@@ -0,0 +1,15 @@
+use std::sync::Arc;
+use summer_macros::component;
+
+#[derive(Default)]
+struct Repository;
+
+#[component]
+struct Service {
+    #[autowired]
+    repository: Arc<Repository>,
+    #[autowired]
+    name: String,
+}
+
+fn main() {}
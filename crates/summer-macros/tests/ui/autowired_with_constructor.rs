@@ -0,0 +1,21 @@
+use std::sync::Arc;
+use summer_macros::{component, constructor};
+
+#[component]
+#[derive(Default)]
+struct Repository;
+
+#[component]
+struct Service {
+    #[autowired]
+    repository: Arc<Repository>,
+}
+
+#[constructor]
+impl Service {
+    fn new(repository: Arc<Repository>) -> Self {
+        Service { repository }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use summer_macros::component;
+
+struct Endpoint(String);
+
+/// Has no `#[autowired]` fields, no `#[constructor]` impl and no `Default` implementation.
+#[component]
+struct Client {
+    endpoint: Endpoint,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use summer_core::ApplicationEvent;
+use summer_macros::{component, event_listener};
+
+struct Started;
+
+impl ApplicationEvent for Started {}
+
+#[component]
+#[derive(Default)]
+struct Service;
+
+impl Service {
+    #[event_listener]
+    fn on_started(&self, _event: &Started) {}
+}
+
+fn main() {}
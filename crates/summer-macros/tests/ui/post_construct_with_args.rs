@@ -0,0 +1,14 @@
+use summer_macros::{component, post_construct};
+
+#[component]
+#[derive(Default)]
+struct Service;
+
+impl Service {
+    #[post_construct]
+    fn init(&self, retries: u32) {
+        let _ = retries;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use std::sync::Arc;
+use summer_macros::component;
+
+#[component]
+#[derive(Default)]
+struct Repository;
+
+#[component]
+#[derive(Default)]
+struct Mailer;
+
+#[component]
+struct Service {
+    #[autowired]
+    #[qualifier(Mailer::BEAN)]
+    repository: Arc<Repository>,
+}
+
+fn main() {}